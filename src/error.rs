@@ -0,0 +1,139 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright 2019 Joyent, Inc.
+//
+extern crate reqwest;
+extern crate serde_json;
+
+use redfish::{RedfishErrorInfo, RedfishMessage};
+
+use std::error::Error;
+use std::fmt;
+
+//
+// Every public function in this crate returns a RedfishError on failure, so
+// that callers can distinguish between, for example, a BMC that rejected our
+// credentials and one that simply doesn't implement the requested action.
+//
+#[derive(Debug)]
+pub enum RedfishError {
+    // The request couldn't be sent or the response couldn't be read.
+    Transport(reqwest::Error),
+    // The service returned a non-success status code.  If the body contained
+    // a Redfish error object, it is parsed into "error".
+    Http { status: u16, body: String, error: Option<RedfishErrorInfo> },
+    // The service rejected our credentials or session, or the user isn't
+    // privileged to make the request.  As with Http, any Redfish error object
+    // in the body is parsed into "error".
+    Auth { msg: String, error: Option<RedfishErrorInfo> },
+    // The service doesn't implement the requested action.
+    NotSupported { action: String },
+    // The response from "uri" didn't match the expected schema.
+    Parse { uri: String, source: serde_json::Error },
+    // The requested resource doesn't exist.
+    NotFound { id: String },
+    // The request was invalid before it was sent.
+    Config(String),
+    // An asynchronous operation, tracked by the Task at "uri", didn't
+    // complete successfully.
+    Task { uri: String, state: String, messages: Vec<RedfishMessage> },
+    // The resource at "uri" didn't reach "state" in the time allowed.
+    Timeout { uri: String, state: String },
+    // The service accepted a change to "uri", but reading it back shows
+    // "actual" rather than "expected".
+    Verify { uri: String, expected: String, actual: String },
+}
+
+impl fmt::Display for RedfishError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RedfishError::Transport(e) => write!(f, "Request Failed! - {}", e),
+            RedfishError::Http { status, error, .. } => {
+                write!(f, "Request Failed! - Status Code: {}", status)?;
+                if let Some(info) = error {
+                    write!(f, "{}", info)?;
+                }
+                Ok(())
+            }
+            RedfishError::Auth { msg, error } => {
+                write!(f, "Authentication Failed! - {}", msg)?;
+                if let Some(info) = error {
+                    write!(f, "{}", info)?;
+                }
+                Ok(())
+            }
+            RedfishError::NotSupported { action } => {
+                write!(f, "Request Failed! Requested action not supported: {}", action)
+            }
+            RedfishError::Parse { uri, source } => {
+                write!(f, "Failed to parse response from {}: {}", uri, source)
+            }
+            RedfishError::NotFound { id } => write!(f, "Not found: {}", id),
+            RedfishError::Config(msg) => write!(f, "{}", msg),
+            RedfishError::Task { uri, state, messages } => {
+                write!(f, "Task {} did not complete - TaskState: {}", uri, state)?;
+                for msg in messages {
+                    write!(f, "\n  {}", msg)?;
+                }
+                Ok(())
+            }
+            RedfishError::Timeout { uri, state } => {
+                write!(f, "Timed out waiting for {} to reach {}", uri, state)
+            }
+            RedfishError::Verify { uri, expected, actual } => {
+                write!(f, "Change to {} not applied - expected {}, BMC reports {}",
+                    uri, expected, actual)
+            }
+        }
+    }
+}
+
+impl Error for RedfishError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RedfishError::Transport(e) => Some(e),
+            RedfishError::Parse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for RedfishError {
+    fn from(e: reqwest::Error) -> RedfishError {
+        RedfishError::Transport(e)
+    }
+}
+
+impl fmt::Display for RedfishErrorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        //
+        // The top-level message is usually just a generic pointer to the
+        // extended information, so only show it if that's all we have.
+        //
+        if self.extended_info.is_empty() {
+            if let Some(message) = &self.message {
+                write!(f, "\n  {}", message)?;
+            }
+        }
+        for msg in &self.extended_info {
+            write!(f, "\n  {}", msg)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RedfishMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{}: {}", self.id, message)?,
+            None => write!(f, "{}: {}", self.id, self.args.join(", "))?,
+        }
+        if let Some(resolution) = &self.resolution {
+            write!(f, "\n    Resolution: {}", resolution)?;
+        }
+        Ok(())
+    }
+}
@@ -9,32 +9,39 @@ extern crate reqwest;
 extern crate serde_json;
 
 extern crate serde;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
-mod redfish;
+pub mod redfish;
 use redfish::{
-    RedfishChassis, RedfishCollection, RedfishEthernetIntf, RedfishManager, RedfishPower,
-    RedfishProcessor, RedfishRootService, RedfishStatus, RedfishSystem, RedfishThermal
+    RedfishActionReset, RedfishAttribute, RedfishAttributeRegistry, RedfishBios,
+    RedfishBiosSettings, RedfishBootOption, RedfishCertIdentifier, RedfishCertificate,
+    RedfishChassis, RedfishCollection, RedfishDrive, RedfishErrorResponse, RedfishEthernetIntf,
+    RedfishLogEntry, RedfishLogService, RedfishManager, RedfishMember, RedfishMemory,
+    RedfishMessageRegistry, RedfishMessageRegistryFile, RedfishPower, RedfishProcessor,
+    RedfishProtocolFeatures, RedfishRootService, RedfishSecureBoot, RedfishSecureBootDatabase,
+    RedfishSessionService, RedfishSoftwareInventory, RedfishStatus, RedfishStorage, RedfishSystem,
+    RedfishSystemSettings, RedfishTask, RedfishThermal, RedfishUpdateService, RedfishVirtualMedia,
+    RedfishVolume
 };
 
-use std::error::Error;
-use std::fmt;
-
-#[derive(Debug)]
-struct SimpleError(String);
-
-impl Error for SimpleError {}
+mod error;
+pub use error::RedfishError;
 
-impl fmt::Display for SimpleError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
 
 enum HTTPReqType {
     Get,
     Patch,
     Post,
     Put,
+    Delete,
 }
 
 impl fmt::Display for HTTPReqType {
@@ -44,10 +51,33 @@ impl fmt::Display for HTTPReqType {
             HTTPReqType::Patch => write!(f, "PATCH"),
             HTTPReqType::Post => write!(f, "POST"),
             HTTPReqType::Put => write!(f, "PUT"),
+            HTTPReqType::Delete => write!(f, "DELETE"),
         }
     }
 }
 
+//
+// The body of a request.  Most requests carry JSON, but firmware images are
+// pushed either as raw binary data or as a multipart form.
+//
+enum RequestBody {
+    Empty,
+    Json(String),
+    Binary(Vec<u8>),
+    Multipart(reqwest::multipart::Form),
+}
+
+//
+// The parts of a response that callers need in order to follow an operation
+// that the service completes asynchronously.
+//
+#[derive(Debug)]
+pub struct RedfishResponse {
+    pub status: u16,
+    pub location: Option<String>,
+    pub body: String,
+}
+
 #[derive(Debug)]
 pub struct RedfishUtilCmd {
     pub cmd: String,
@@ -60,6 +90,45 @@ impl RedfishUtilCmd {
     }
 }
 
+//
+// How the information commands present their results.  Text is intended for
+// humans; JSON emits a single document per command for use by scripts.
+//
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+//
+// Options that modify how the action commands behave.
+//
+#[derive(Debug, Default)]
+pub struct CmdOptions {
+    // Let a power command use a related ResetType if the one requested isn't
+    // supported, e.g. ForceRestart in place of GracefulRestart.
+    pub fallback: bool,
+    // If set, wait up to this many seconds for a power command to bring the
+    // system to the expected PowerState.
+    pub wait: Option<u64>,
+    // The system to act on, for commands whose ARG is something else.
+    pub system: Option<String>,
+    // The BootSourceOverrideEnabled and BootSourceOverrideMode to set with a
+    // boot override.
+    pub boot_enabled: Option<String>,
+    pub boot_mode: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct Config {
     pub debug: bool,
@@ -68,6 +137,8 @@ pub struct Config {
     pub passwd: String,
     pub host: String,
     pub cmd: RedfishUtilCmd,
+    pub output: OutputFormat,
+    pub cmd_opts: CmdOptions,
 }
 
 impl Config {
@@ -78,6 +149,7 @@ impl Config {
         passwd: String,
         host: String,
         cmd: RedfishUtilCmd,
+        output: OutputFormat,
     ) -> Config {
         Config {
             debug,
@@ -86,466 +158,3467 @@ impl Config {
             passwd,
             host,
             cmd,
+            output,
+            cmd_opts: CmdOptions::default(),
         }
     }
 }
 
-fn print_status(status: &RedfishStatus, pad: usize) {
-    let pad = " ".repeat(pad);
-
-    if status.state.is_some() {
-        println!(
-            "{0}{1: <20} {2}",
-            pad,
-            "State:",
-            status.state.as_ref().unwrap()
-        );
-    }
-    if status.health.is_some() {
-        println!(
-            "{0}{1: <20} {2}",
-            pad,
-            "Health:",
-            status.health.as_ref().unwrap()
-        );
-    }
-    if status.health_rollup.is_some() {
-        println!(
-            "{0}{1: <20} {2}",
-            pad,
-            "Health Rollup:",
-            status.health_rollup.as_ref().unwrap()
-        );
-    }
+//
+// An authenticated Redfish session.  "token" is the value of the X-Auth-Token
+// header returned when the session was created and "uri" is the session
+// resource that must be deleted to log out.
+//
+#[derive(Debug)]
+pub struct RedfishSession {
+    pub token: String,
+    pub uri: String,
 }
 
-fn ethernet_get(config: &Config, uri: &str) 
-    -> Result<Vec<RedfishEthernetIntf>, Box<dyn Error>> {
+//
+// TaskState values after which a task will make no further progress.
+//
+const TASK_DONE_STATES: [&str; 4] = ["Completed", "Exception", "Killed", "Cancelled"];
 
-    let resp = do_get_request(&config, uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
-    let mut intfs = Vec::new();
+//
+// Asynchronous operations are polled starting at TASK_POLL_MIN seconds, backing
+// off to at most TASK_POLL_MAX seconds between polls, for up to TASK_TIMEOUT
+// seconds in total.
+//
+const TASK_POLL_MIN: u64 = 1;
+const TASK_POLL_MAX: u64 = 30;
+const TASK_TIMEOUT: u64 = 3600;
 
-    for mmbr in &coll.members {
-        let resp = do_get_request(&config, &mmbr.uri)?;
-        let mut eth: RedfishEthernetIntf = serde_json::from_str(&resp)?;
-        eth.uri = mmbr.uri.clone();
-        intfs.push(eth);
-    }
-    Ok(intfs)
+//
+// Power commands that wait for a PowerState poll every POWER_POLL_INTERVAL
+// seconds, for up to POWER_WAIT_TIMEOUT seconds unless told otherwise.
+//
+const POWER_POLL_INTERVAL: u64 = 5;
+pub const POWER_WAIT_TIMEOUT: u64 = 300;
+
+//
+// A connection to the Redfish service on a single BMC.  The underlying HTTP
+// client is created once and reused for every request, so that requests share
+// pooled connections rather than each paying for a new TLS handshake.
+//
+#[derive(Debug)]
+pub struct RedfishClient {
+    host: String,
+    user: String,
+    passwd: String,
+    insecure: bool,
+    pub debug: bool,
+    // Called each time an asynchronous operation's Task is polled.
+    pub task_progress: Option<fn(&RedfishTask)>,
+    client: reqwest::Client,
+    session: Option<RedfishSession>,
+    root: Option<RedfishRootService>,
 }
 
-fn managers_get(config: &Config) -> Result<Vec<RedfishManager>, Box<dyn Error>> {
-    let uri = "/redfish/v1";
-    let resp = do_get_request(&config, &uri)?;
-    let rootsvc: RedfishRootService = serde_json::from_str(&resp)?;
-    let resp = do_get_request(&config, &rootsvc.mngrs.uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
-    let mut mngrs = Vec::new();
+impl RedfishClient {
+    pub fn new(
+        host: &str,
+        user: &str,
+        passwd: &str,
+        insecure: bool,
+        debug: bool,
+    ) -> Result<RedfishClient, RedfishError> {
+        let client = reqwest::Client::builder()
+            .danger_accept_invalid_certs(insecure)
+            .build()?;
 
-    for mmbr in &coll.members {
-        let resp = do_get_request(&config, &mmbr.uri)?;
-        let mut mngr: RedfishManager = serde_json::from_str(&resp)?;
-        mngr.uri = mmbr.uri.clone();
-        mngrs.push(mngr);
+        Ok(RedfishClient {
+            host: host.to_string(),
+            user: user.to_string(),
+            passwd: passwd.to_string(),
+            insecure,
+            debug,
+            task_progress: None,
+            client,
+            session: None,
+            root: None,
+        })
     }
-    Ok(mngrs)
-}
 
-fn power_get(config: &Config, uri: &str) -> Result<RedfishPower, Box<dyn Error>> {
-    let resp = do_get_request(&config, uri)?;
-    let power: RedfishPower = serde_json::from_str(&resp)?;
+    pub fn from_config(config: &Config) -> Result<RedfishClient, RedfishError> {
+        RedfishClient::new(
+            &config.host,
+            &config.user,
+            &config.passwd,
+            config.insecure,
+            config.debug,
+        )
+    }
 
-    Ok(power)
-}
+    //
+    // The connection settings can only be given when the client is created,
+    // as the pooled HTTP client is built from them.
+    //
+    pub fn host(&self) -> &str {
+        &self.host
+    }
 
-fn processors_get(config: &Config, uri: &str)
-    -> Result<Vec<RedfishProcessor>, Box<dyn Error>> {
+    pub fn user(&self) -> &str {
+        &self.user
+    }
 
-    let resp = do_get_request(&config, uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
-    let mut chips = Vec::new();
+    pub fn insecure(&self) -> bool {
+        self.insecure
+    }
 
-    for mmbr in &coll.members {
-        let resp = do_get_request(&config, &mmbr.uri)?;
-        let mut chip: RedfishProcessor = serde_json::from_str(&resp)?;
-        chip.uri = mmbr.uri.clone();
-        chips.push(chip);
+    pub fn session(&self) -> Option<&RedfishSession> {
+        self.session.as_ref()
+    }
+
+    //
+    // The service root, as read when we logged in.  A client that hasn't
+    // logged in reads it from the service each time.
+    //
+    pub fn root(&self) -> Result<Cow<'_, RedfishRootService>, RedfishError> {
+        match &self.root {
+            Some(root) => Ok(Cow::Borrowed(root)),
+            None => Ok(Cow::Owned(self.get("/redfish/v1")?)),
+        }
     }
-    Ok(chips)
-}
 
-fn thermal_get(config: &Config, uri: &str) -> Result<RedfishThermal, Box<dyn Error>> {
-    let resp = do_get_request(&config, uri)?;
-    let thermal: RedfishThermal = serde_json::from_str(&resp)?;
+    //
+    // The query parameters supported by the service, as advertised by the
+    // service root when we logged in.
+    //
+    pub fn features(&self) -> Option<&RedfishProtocolFeatures> {
+        self.root.as_ref().and_then(|r| r.protocol_features.as_ref())
+    }
 
-    Ok(thermal)
-}
+    //
+    // Whether members of a collection can be read inline with
+    // $expand=.($levels=1).
+    //
+    pub fn expand_supported(&self) -> bool {
+        self.features()
+            .and_then(|f| f.expand_query.as_ref())
+            .is_some_and(|e| e.no_links == Some(true) && e.levels == Some(true))
+    }
 
-fn show_chassis(config: &Config) -> Result<(), Box<dyn Error>> {
-    let uri = "/redfish/v1/Chassis";
-    let resp = do_get_request(&config, &uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
+    pub fn select_supported(&self) -> bool {
+        self.features().is_some_and(|f| f.select_query == Some(true))
+    }
 
-    println!("Number of Chassis: {}", coll.members.len());
-    for mmbr in &coll.members {
-        let resp = do_get_request(&config, &mmbr.uri)?;
-        let mut chassis: RedfishChassis = serde_json::from_str(&resp)?;
-        chassis.uri = mmbr.uri.to_string();
-        println!("Chassis Details");
-        println!("  {0: <20} {1}", "Name:", chassis.name);
-        println!("  {0: <20} {1}", "Type:", chassis.chassis_type);
-	if chassis.manufacturer.is_some() {
-            println!("  {0: <20} {1}", "Manufacturer:", chassis.manufacturer.as_mut().unwrap());
-        }
-        println!("  {0: <20} {1}", "Serial Number:", chassis.serial_num);
-        println!("  {0: <20} {1}", "Part Number:", chassis.part_num);
-        if chassis.status.is_some() {
-            print_status(&chassis.status.unwrap(), 2);
+    //
+    // Create a Redfish session using the Sessions collection advertised by the
+    // service root.  If the service doesn't support sessions, then we silently
+    // fall back to basic authentication.
+    //
+    pub fn login(&mut self) -> Result<(), RedfishError> {
+        if self.session.is_some() {
+            return Ok(());
         }
 
-        if chassis.power.is_some() {
-            let mut power = power_get(&config, &chassis.power.as_mut().unwrap().uri)?;
-            println!("\n  Power Supplies");
-            for psu in &mut power.power_supplies {
-                println!("    {0: <20} {1}", "Label:", psu.name);
-                if psu.model.is_some() {
-                    println!("    {0: <20} {1}", "Model:", psu.model.as_mut().unwrap());
+        //
+        // Keep the service root so that later lookups of its links don't
+        // need to read it again.  This is done before creating the session
+        // so that it is also kept when we fall back to basic authentication.
+        //
+        self.root = Some(self.get("/redfish/v1")?);
+        let rootsvc = self.root.as_ref().unwrap();
+
+        let sessions_uri = match rootsvc.links.as_ref().and_then(|l| l.sessions.as_ref()) {
+            Some(sessions) => sessions.uri.clone(),
+            None => match &rootsvc.session_svc {
+                Some(svc) => {
+                    let sesssvc: RedfishSessionService = self.get(&svc.uri)?;
+                    sesssvc.sessions.uri
                 }
-                if psu.serial.is_some() {
-                    println!("    {0: <20} {1}", "Serial:", psu.serial.as_mut().unwrap());
+                None => return Ok(()),
+            },
+        };
+
+        let data = serde_json::json!({
+            "UserName": self.user,
+            "Password": self.passwd,
+        });
+        let mut response = self.send(HTTPReqType::Post, &sessions_uri,
+            RequestBody::Json(data.to_string()))?;
+
+        let token = match response.headers().get("X-Auth-Token") {
+            Some(t) => match t.to_str() {
+                Ok(t) => t.to_string(),
+                Err(_) => {
+                    return Err(RedfishError::Auth {
+                        msg: "Session creation failed! Invalid X-Auth-Token returned".to_string(),
+                        error: None,
+                    });
                 }
-                print_status(&psu.status, 4);
-                println!();
+            },
+            None => {
+                return Err(RedfishError::Auth {
+                    msg: "Session creation failed! No X-Auth-Token returned".to_string(),
+                    error: None,
+                });
             }
-        }
-        if chassis.thermal.is_some() {
-            let mut thermal = thermal_get(&config, &chassis.thermal.as_mut().unwrap().uri)?;
-            println!("  Fans");
-            for fan in &mut thermal.fans {
-                //
-                // "FanName" was deprecated in favor of "Name".  We need need to
-                // handle both cases.
-                //
-                if fan.name.is_some() {
-                    println!("    {0: <20} {1}", "Label:", fan.name.as_mut().unwrap());
-                } else if fan.fanname.is_some() {
-                    println!("    {0: <20} {1}", "Label:", fan.fanname.as_mut().unwrap());
-                }
-                print_status(&fan.status, 4);
-                println!();
+        };
+
+        //
+        // The session URI is normally in the Location header, but fall back to
+        // the resource's @odata.id if it isn't.
+        //
+        let location = response.headers().get(reqwest::header::LOCATION)
+            .and_then(|loc| loc.to_str().ok())
+            .map(uri_path);
+        let uri = match location {
+            Some(loc) => loc,
+            None => {
+                let sess: RedfishMember = parse_json(&sessions_uri, &response.text()?)?;
+                uri_path(&sess.uri)
             }
+        };
+
+        if self.debug {
+            eprintln!("Created session: {}\n", uri);
         }
+        self.session = Some(RedfishSession { token, uri });
+        Ok(())
     }
-    Ok(())
-}
 
-fn print_ethernet_intfs(eths: &mut [RedfishEthernetIntf])
-{
-    println!("\n  Ethernet Interfaces");
-    for eth in eths {
-        println!("    {0: <20} {1}", "Label:", eth.name);
-        if eth.mac_addr.is_some() {
-            println!("    {0: <20} {1}", "MAC Address:",
-                eth.mac_addr.as_mut().unwrap());
-        }
-        if eth.link_state.is_some() {
-            println!("    {0: <20} {1}", "Link State:",
-                eth.link_state.as_mut().unwrap());
-        }
-        if eth.ipv4.is_some() {
-            let ipv4addrs = eth.ipv4.as_mut().unwrap();
-            for ipv4 in ipv4addrs {
-                println!("    {0: <20} {1}", "IPv4 Address:", ipv4.address);
-                println!("    {0: <20} {1}", "IPv4 Subnet:", ipv4.subnet);
-                println!("    {0: <20} {1}", "IPv4 Gateway:", ipv4.gateway);
-                println!("    {0: <20} {1}", "IPv4 Source:", ipv4.origin);
-            }
+    pub fn logout(&mut self) -> Result<(), RedfishError> {
+        if let Some(uri) = self.session.as_ref().map(|s| s.uri.clone()) {
+            let result = self.delete(&uri);
+            self.session = None;
+            result?;
         }
-        println!("    Status");
-        print_status(&eth.status, 6);
-        println!();
+        Ok(())
     }
-}
 
-fn show_system(config: &Config) -> Result<(), Box<dyn Error>> {
-    println!("Managers");
-    let mut mngrs = managers_get(&config)?;
+    pub fn get<T: DeserializeOwned>(&self, uri: &str) -> Result<T, RedfishError> {
+        let resp = self.request(HTTPReqType::Get, uri, RequestBody::Empty)?;
+        parse_json(uri, &resp.body)
+    }
 
-    for mngr in &mut mngrs {
-        println!("  {0: <20} {1}", "Type:", mngr.mngr_type);
-        if mngr.model.is_some() {
-            println!("  {0: <20} {1}", "Model:", mngr.model.as_mut().unwrap());
-        }
-        if mngr.fw_version.is_some() {
-            println!(
-                "  {0: <20} {1}",
-                "Firmware Version:",
-                mngr.fw_version.as_mut().unwrap()
-            );
-        }
-        let mut supp_cons = String::new();
-        if mngr.cons_graph.is_some() && mngr.cons_graph.as_mut().unwrap().enabled {
-            supp_cons.push_str("KVM ");
-        }
-        if mngr.cons_serial.is_some() && mngr.cons_serial.as_mut().unwrap().enabled {
-            supp_cons.push_str("Serial ");
-        }
-        if mngr.cons_shell.is_some() && mngr.cons_shell.as_mut().unwrap().enabled {
-            supp_cons.push_str("CLI");
-        }
-        println!("  {0: <20} {1}", "Console Types:", supp_cons);
-        println!("  Status");
-        print_status(&mngr.status, 4);
-        if mngr.eth_intfs.is_some() {
-            let mut eths = ethernet_get(&config, &mngr.eth_intfs.as_mut().unwrap().uri)?;
-            print_ethernet_intfs(eths.as_mut_slice());
+    //
+    // Iterate over the members of the collection at "uri", following
+    // Members@odata.nextLink as needed.
+    //
+    pub fn collection<T: DeserializeOwned>(&self, uri: &str) -> RedfishCollectionIter<'_, T> {
+        RedfishCollectionIter {
+            client: self,
+            next: Some(uri.to_string()),
+            page: Vec::new().into_iter(),
+            count: None,
+            seen: 0,
         }
-        println!();
     }
 
-    let uri = "/redfish/v1/Systems";
-    let resp = do_get_request(&config, &uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
+    //
+    // Iterate over the links to the members of the collection at "uri".
+    //
+    pub fn members(&self, uri: &str) -> RedfishCollectionIter<'_, RedfishMember> {
+        self.collection(uri)
+    }
 
-    for mmbr in &coll.members {
-        let resp = do_get_request(&config, &mmbr.uri)?;
-        let mut system: RedfishSystem = serde_json::from_str(&resp)?;
-        system.uri = mmbr.uri.to_string();
-        println!("System Details");
-        println!("  {0: <20} {1}", "Type:", system.sys_type);
-        println!("  {0: <20} {1}", "Manufacturer:", system.manufacturer);
-        println!("  {0: <20} {1}", "Model:", system.model);
-        println!("  {0: <20} {1}", "Serial Number:", system.serial_num);
-        println!("  {0: <20} {1}", "Part Number:", system.part_num);
-        if system.sku.is_some() {
-            println!("  {0: <20} {1}", "SKU:", system.sku.unwrap());
-        };
-        if system.uuid.is_some() {
-            println!("  {0: <20} {1}", "UUID:", system.uuid.unwrap());
+    pub fn patch<B: Serialize>(&self, uri: &str, body: &B) -> Result<String, RedfishError> {
+        let resp = self.request(HTTPReqType::Patch, uri, RequestBody::Json(body_json(body)?))?;
+        Ok(resp.body)
+    }
+
+    pub fn post<B: Serialize>(&self, uri: &str, body: &B) -> Result<String, RedfishError> {
+        Ok(self.post_action(uri, body)?.body)
+    }
+
+    pub fn put<B: Serialize>(&self, uri: &str, body: &B) -> Result<String, RedfishError> {
+        let resp = self.request(HTTPReqType::Put, uri, RequestBody::Json(body_json(body)?))?;
+        Ok(resp.body)
+    }
+
+    pub fn delete(&self, uri: &str) -> Result<String, RedfishError> {
+        Ok(self.request(HTTPReqType::Delete, uri, RequestBody::Empty)?.body)
+    }
+
+    //
+    // Like post(), but returns the whole response so that the caller can
+    // follow any Task that the action started.
+    //
+    pub fn post_action<B: Serialize>(&self, uri: &str, body: &B)
+        -> Result<RedfishResponse, RedfishError> {
+
+        self.request(HTTPReqType::Post, uri, RequestBody::Json(body_json(body)?))
+    }
+
+    pub fn post_binary(&self, uri: &str, data: Vec<u8>) -> Result<RedfishResponse, RedfishError> {
+        self.request(HTTPReqType::Post, uri, RequestBody::Binary(data))
+    }
+
+    pub fn post_multipart(&self, uri: &str, form: reqwest::multipart::Form)
+        -> Result<RedfishResponse, RedfishError> {
+
+        self.request(HTTPReqType::Post, uri, RequestBody::Multipart(form))
+    }
+
+    //
+    // Fill in any messages in an error response that only carry a MessageId,
+    // by looking them up in the message registries published by the service.
+    // This is best effort; messages that can't be found are left as they are.
+    //
+    pub fn messages_expand(&self, err: &mut RedfishError) {
+        let info = match err {
+            RedfishError::Http { error: Some(info), .. } => info,
+            RedfishError::Auth { error: Some(info), .. } => info,
+            _ => return,
         };
-        println!("  {0: <20} {1}", "BIOS Version:", system.bios_vers);
-        if system.pwr_state.is_some() {
-            println!("  {0: <20} {1}", "Power Status:", system.pwr_state.unwrap());
-        }
-        if system.locate_led.is_some() {
-            println!("  {0: <20} {1}", "Locate LED:", system.locate_led.unwrap());
-        }
-        println!("  Status");
-        print_status(&system.memory.status, 4);
 
-        let mut chips = processors_get(&config, &system.chips.uri)?;
-        println!("\n  Processors");
-        for chip in &mut chips {
-            println!();
-            println!("    {0: <20} {1}", "Label:", chip.socket);
-            println!("    {0: <20} {1}", "Manufacturer:", chip.manufacturer);
-            println!("    {0: <20} {1}", "Brand:", chip.brand);
-            if chip.id.family.is_some() {
-                println!("    {0: <20} {1}", "Family:", chip.id.family.as_mut().unwrap());
-            }
-            if chip.id.model.is_some() {
-                println!("    {0: <20} {1}", "Model:", chip.id.model.as_mut().unwrap());
+        let mut registries: HashMap<String, Option<RedfishMessageRegistry>> = HashMap::new();
+        for msg in &mut info.extended_info {
+            if msg.message.is_some() {
+                continue;
             }
-            if chip.id.stepping.is_some() {
-                println!("    {0: <20} {1}", "Stepping:", chip.id.stepping.as_mut().unwrap());
+            let prefix = msg.id.split('.').next().unwrap_or_default().to_string();
+            let key = msg.id.rsplit('.').next().unwrap_or_default();
+
+            if !registries.contains_key(&prefix) {
+                let registry = self.registry_get(&prefix).unwrap_or(None);
+                registries.insert(prefix.clone(), registry);
             }
-            if chip.id.ucode_version.is_some() {
-                println!(
-                    "    {0: <20} {1}",
-                    "Ucode Version:",
-                    chip.id.ucode_version.as_mut().unwrap()
-                );
+            let regmsg = registries[&prefix].as_ref().and_then(|r| r.messages.get(key));
+            if let Some(regmsg) = regmsg {
+                msg.message = Some(message_format(&regmsg.message, &msg.args));
+                if msg.resolution.is_none() {
+                    msg.resolution = regmsg.resolution.clone();
+                }
             }
-            println!("    {0: <20} {1} MHz", "Speed:", chip.speed_mhz);
-            println!("    {0: <20} {1}", "Total Cores:", chip.ncores);
-            println!("    {0: <20} {1}", "Total Threads:", chip.nthreads);
-            println!("    Status");
-            print_status(&chip.status, 6);
         }
+    }
 
-        println!("\n  Memory");
-        println!(
-            "    {0: <20} {1} GiB",
-            "Total RAM:", system.memory.total_memory
-        );
+    //
+    // Find the registry named "name" in the service's Registries collection.
+    // Registries are matched on their prefix (e.g. "Base" for "Base.1.4.0"),
+    // so a message's prefix or the full AttributeRegistry name of a resource
+    // will do.
+    //
+    pub fn registry_get<T: DeserializeOwned>(&self, name: &str)
+        -> Result<Option<T>, RedfishError> {
 
-        if system.eth_intfs.is_some() {
-            let mut eths = ethernet_get(&config, &system.eth_intfs.unwrap().uri)?;
-            print_ethernet_intfs(eths.as_mut_slice());
+        let prefix = name.split('.').next();
+        let uri = match &self.root()?.registries {
+            Some(registries) => registries.uri.clone(),
+            None => return Ok(None),
+        };
+        for mmbr in self.members(&uri) {
+            let mmbr = mmbr?;
+            let file: RedfishMessageRegistryFile = self.get(&mmbr.uri)?;
+            if file.registry != name && file.registry.split('.').next() != prefix {
+                continue;
+            }
+
+            //
+            // A registry may be published in several languages.  Prefer the
+            // English version.
+            //
+            let loc = file.location.iter()
+                .find(|l| l.language.as_deref() == Some("en"))
+                .or_else(|| file.location.first());
+            if let Some(uri) = loc.and_then(|l| l.uri.as_ref()) {
+                return Ok(Some(self.get(&uri_path(uri))?));
+            }
         }
+        Ok(None)
     }
-    Ok(())
-}
 
-fn show_version(config: &Config) -> Result<(), Box<dyn Error>> {
-    let uri = "/redfish/v1";
-    let resp = do_get_request(&config, &uri)?;
-    let rootsvc: RedfishRootService = serde_json::from_str(&resp)?;
+    //
+    // Poll the task monitor or Task resource at "uri" until the operation it
+    // tracks has finished, and return the operation's final response.
+    //
+    pub fn task_monitor(&self, uri: &str) -> Result<RedfishResponse, RedfishError> {
+        let start = Instant::now();
+        let mut delay = TASK_POLL_MIN;
 
-    println!("Redfish version: {}", rootsvc.version);
+        loop {
+            let resp = self.request_once(HTTPReqType::Get, uri, RequestBody::Empty)?;
 
-    Ok(())
-}
+            //
+            // While the operation is in progress, a task monitor returns 202
+            // and usually the Task itself.  Once it's done, the monitor returns
+            // the operation's response instead, which needn't be a Task.
+            //
+            let task = serde_json::from_str::<RedfishTask>(&resp.body).ok();
+            if let (Some(task), Some(progress)) = (&task, self.task_progress) {
+                progress(task);
+            }
+            if resp.status != 202 {
+                match task {
+                    None => return Ok(resp),
+                    Some(task) => {
+                        if task.state == "Completed" {
+                            return Ok(resp);
+                        }
+                        if TASK_DONE_STATES.contains(&task.state.as_str()) {
+                            return Err(RedfishError::Task {
+                                uri: uri.to_string(),
+                                state: task.state,
+                                messages: task.messages,
+                            });
+                        }
+                    }
+                }
+            }
 
-fn do_boot(config: &Config, boot_target: &str) -> Result<(), Box<dyn Error>> {
-    let uri = "/redfish/v1/Systems";
-    let resp = do_get_request(&config, &uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
+            if start.elapsed() > Duration::from_secs(TASK_TIMEOUT) {
+                return Err(RedfishError::Task {
+                    uri: uri.to_string(),
+                    state: "timed out".to_string(),
+                    messages: Vec::new(),
+                });
+            }
+            thread::sleep(Duration::from_secs(delay));
+            delay = (delay * 2).min(TASK_POLL_MAX);
+        }
+    }
 
-    let system_uri = match &config.cmd.arg {
-        Some(id) => format!("{}/{}", uri, id),
-        None => coll.members[0].uri.clone(),
-    };
-    let resp = do_get_request(&config, &system_uri)?;
-    let system: RedfishSystem = serde_json::from_str(&resp)?;
+    //
+    // Send a request and, if the service completes it asynchronously, wait
+    // for it to finish.
+    //
+    fn request(&self, req_type: HTTPReqType, uri: &str, body: RequestBody)
+        -> Result<RedfishResponse, RedfishError> {
 
-    match system.boot {
-        Some(_) => {
-            let data = format!(
-                "{{ \"Boot\": {{ \"BootSourceOverrideEnabled\":\"Once\", \
-                 \"BootSourceOverrideTarget\": \"{}\" }}}}",
-                boot_target
-            );
-            do_http_request(&config, HTTPReqType::Patch, &system_uri, Some(data.clone()))?;
-            Ok(())
+        let is_get = matches!(req_type, HTTPReqType::Get);
+        let resp = self.request_once(req_type, uri, body)?;
+
+        if !is_get {
+            if let Some(task_uri) = task_pending(&resp) {
+                if self.debug {
+                    eprintln!("Waiting for task: {}\n", task_uri);
+                }
+                return self.task_monitor(&task_uri);
+            }
         }
-        None => Err(Box::new(SimpleError(
-            "Request Failed! Requested action not supported".to_string(),
-        ))),
+        Ok(resp)
     }
-}
-
-fn do_identify(config: &Config, ledstate: &str) -> Result<(), Box<dyn Error>> {
-    let uri = "/redfish/v1/Systems";
-    let resp = do_get_request(&config, &uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
 
-    let system_uri = match &config.cmd.arg {
-        Some(id) => format!("{}/{}", uri, id),
-        None => coll.members[0].uri.clone(),
-    };
-    let resp = do_get_request(&config, &system_uri)?;
-    let system: RedfishSystem = serde_json::from_str(&resp)?;
+    fn request_once(&self, req_type: HTTPReqType, uri: &str, body: RequestBody)
+        -> Result<RedfishResponse, RedfishError> {
 
-    match system.locate_led {
-        Some(_) => {
-            let data = format!("{{\"IndicatorLED\":\"{}\"}}", ledstate);
-            do_http_request(&config, HTTPReqType::Patch, &system_uri, Some(data.clone()))?;
-            Ok(())
+        let mut response = self.send(req_type, uri, body)?;
+        let location = response.headers().get(reqwest::header::LOCATION)
+            .and_then(|loc| loc.to_str().ok())
+            .map(uri_path);
+        let resp_txt = response.text()?;
+        if self.debug {
+            eprintln!("Response:\n{}\n", &resp_txt);
         }
-        None => Err(Box::new(SimpleError(
-            "Request Failed! Requested action not supported".to_string(),
-        ))),
+        Ok(RedfishResponse {
+            status: response.status().as_u16(),
+            location,
+            body: resp_txt,
+        })
     }
-}
 
-fn do_power(config: &Config, pwrstate: &str) -> Result<(), Box<dyn Error>> {
-    let uri = "/redfish/v1/Systems";
-    let resp = do_get_request(&config, &uri)?;
-    let coll: RedfishCollection = serde_json::from_str(&resp)?;
+    fn send(&self, req_type: HTTPReqType, uri: &str, body: RequestBody)
+        -> Result<reqwest::Response, RedfishError> {
 
-    let system_uri = match &config.cmd.arg {
-        Some(id) => format!("{}/{}", uri, id),
-        None => coll.members[0].uri.clone(),
-    };
-    let resp = do_get_request(&config, &system_uri)?;
-    let system: RedfishSystem = serde_json::from_str(&resp)?;
+        let req_url = format!("https://{}{}", self.host, uri);
 
-    match system.actions.reset {
-        Some(action) => {
-            let data = format!("{{\"ResetType\":\"{}\"}}", pwrstate);
-            do_http_request(&config, HTTPReqType::Post, &action.target, Some(data.clone()))?;
-            Ok(())
+        if self.debug {
+            eprintln!("Sending {} Request: {}", req_type, req_url);
+            match &body {
+                RequestBody::Empty => (),
+                RequestBody::Json(data) => eprintln!("Body:\n{}", redact(data)),
+                RequestBody::Binary(data) => eprintln!("Body: {} bytes", data.len()),
+                RequestBody::Multipart(form) => {
+                    eprintln!("Body: multipart/form-data; boundary={}", form.boundary())
+                }
+            }
         }
-        None => Err(Box::new(SimpleError(
-            "Request Failed! Requested action not supported".to_string(),
-        ))),
-    }
-}
 
-fn do_http_request(config: &Config, req_type: HTTPReqType, uri: &str, mut data: Option<String>)
-    -> Result<String, Box<dyn Error>> {
+        //
+        // Uploading a firmware image to a BMC can easily take longer than the
+        // default request timeout, so uploads use a client without one.
+        //
+        let upload_client;
+        let client = match body {
+            RequestBody::Binary(_) | RequestBody::Multipart(_) => {
+                upload_client = reqwest::Client::builder()
+                    .danger_accept_invalid_certs(self.insecure)
+                    .timeout(None)
+                    .build()?;
+                &upload_client
+            }
+            _ => &self.client,
+        };
 
-    let req_url = format!("https://{}{}", config.host, uri);
+        let mut request = match req_type {
+            HTTPReqType::Get => client.get(&req_url),
+            HTTPReqType::Patch => client.patch(&req_url),
+            HTTPReqType::Post => client.post(&req_url),
+            HTTPReqType::Put => client.put(&req_url),
+            HTTPReqType::Delete => client.delete(&req_url),
+        };
 
-    let client = reqwest::Client::builder()
-        .danger_accept_invalid_certs(config.insecure)
-        .build()?;
+        //
+        // Once a session has been established, authenticate with its token
+        // rather than sending the user's credentials on every request.
+        //
+        request = match &self.session {
+            Some(session) => request.header("X-Auth-Token", session.token.as_str()),
+            None => request.basic_auth(&self.user, Some(&self.passwd)),
+        };
 
-    if config.debug {
-        eprintln!("Sending {} Request: {}", req_type.to_string(), req_url);
-        if data.is_some() {
-            eprintln!("Body:\n{}", data.as_mut().unwrap());
-        }
-    }
+        request = match body {
+            RequestBody::Empty => request,
+            RequestBody::Json(data) => request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(data),
+            RequestBody::Binary(data) => request
+                .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+                .body(data),
+            RequestBody::Multipart(form) => request.multipart(form),
+        };
 
-    let mut response = match req_type {
-        HTTPReqType::Get => {
-            client.get(&req_url)
-                .basic_auth(&config.user, Some(&config.passwd))
-                .send()?
-        },
-        HTTPReqType::Patch => {
-            client.patch(&req_url)
-                .basic_auth(&config.user, Some(&config.passwd))
-                .body(data.unwrap())
-                .send()?
-        },
-        HTTPReqType::Post => {
-            client.post(&req_url)
-                .basic_auth(&config.user, Some(&config.passwd))
-                .body(data.unwrap())
-                .send()?
-        },
-        HTTPReqType::Put => {
-            client.put(&req_url)
-                .basic_auth(&config.user, Some(&config.passwd))
-                .body(data.unwrap())
-                .send()?
-        },
-    };
+        let mut response = request.send()?;
 
-    if response.status().is_success() {
-        let resp_txt = response.text().unwrap();
-        if config.debug {
-            eprintln!("Response:\n{}\n", &resp_txt);
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let body = response.text().unwrap_or_default();
+            if self.debug {
+                eprintln!("Response:\n{}\n", &body);
+            }
+            let error = serde_json::from_str::<RedfishErrorResponse>(&body)
+                .ok()
+                .map(|r| r.error);
+            if status == reqwest::StatusCode::UNAUTHORIZED
+                || status == reqwest::StatusCode::FORBIDDEN {
+                let msg = format!("{} {} - Status Code: {}", req_type, uri, status);
+                Err(RedfishError::Auth { msg, error })
+            } else {
+                Err(RedfishError::Http { status: status.as_u16(), body, error })
+            }
         }
-        Ok(resp_txt)
-    } else {
-        Err(Box::new(SimpleError(format!(
-            "Request Failed! - Status Code: {}",
-            response.status()
-        ))))
     }
 }
 
-fn do_get_request(config: &Config, uri: &str) -> Result<String, Box<dyn Error>> {
-    do_http_request(&config, HTTPReqType::Get, &uri, None)
+//
+// An iterator over the members of a collection, which fetches each page of the
+// collection as it's needed.  Services that page a collection provide a
+// Members@odata.nextLink to the next page.  Iteration ends once
+// Members@odata.count members have been seen, even if the service offers more
+// pages.
+//
+pub struct RedfishCollectionIter<'a, T> {
+    client: &'a RedfishClient,
+    next: Option<String>,
+    page: std::vec::IntoIter<T>,
+    count: Option<usize>,
+    seen: usize,
 }
 
-pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
-    match config.cmd.cmd.as_ref() {
+impl<'a, T: DeserializeOwned> Iterator for RedfishCollectionIter<'a, T> {
+    type Item = Result<T, RedfishError>;
 
-        "nmi" => do_power(&config, "Nmi")?,
-        "off" => do_power(&config, "GracefulShutdown")?,
-        "on" => do_power(&config, "On")?,
-        "reset" => do_power(&config, "GracefulRestart")?,
-        "forceoff" => do_power(&config, "ForceOff")?,
-        "forceon" => do_power(&config, "ForceOn")?,
-        "forcereset" => do_power(&config, "ForceRestart")?,
-        "biossetup" => do_boot(&config, "BiosSetup")?,
-        "identifyoff" => do_identify(&config, "Off")?,
-        "identifyon" => do_identify(&config, "Blinking")?,
-
-        "chassis" => show_chassis(&config)?,
-        "system" => show_system(&config)?,
-        "version" => show_version(&config)?,
+    fn next(&mut self) -> Option<Result<T, RedfishError>> {
+        loop {
+            if self.count.is_some_and(|count| self.seen >= count) {
+                return None;
+            }
+            if let Some(mmbr) = self.page.next() {
+                self.seen += 1;
+                return Some(Ok(mmbr));
+            }
 
-        _ => {
-            panic!("unexpected command");
-        }
-    };
+            let uri = self.next.take()?;
+            let page: RedfishCollection<T> = match self.client.get(&uri) {
+                Ok(page) => page,
+                Err(e) => return Some(Err(e)),
+            };
+            if page.count.is_some() {
+                self.count = page.count;
+            }
 
-    Ok(())
+            //
+            // An empty page means there's nothing more to read, whatever the
+            // service claims.
+            //
+            if !page.members.is_empty() {
+                self.next = page.next_link.map(|link| uri_path(&link));
+            }
+            self.page = page.members.into_iter();
+        }
+    }
+}
+
+impl Drop for RedfishClient {
+    //
+    // Don't leak sessions on the BMC if the caller never logged out.  There's
+    // nothing useful to be done with an error at this point.
+    //
+    fn drop(&mut self) {
+        let _ = self.logout();
+    }
+}
+
+//
+// Hide any password in a request body before it is printed for debugging, as
+// session creation sends the user's credentials in the body.
+//
+fn redact(data: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(data) {
+        Ok(serde_json::Value::Object(mut body)) if body.contains_key("Password") => {
+            body.insert("Password".to_string(), serde_json::json!("********"));
+            serde_json::Value::Object(body).to_string()
+        }
+        _ => data.to_string(),
+    }
+}
+
+//
+// Strip the scheme and authority from a URI, as some services return absolute
+// URLs in the Location header and in links.
+//
+fn uri_path(uri: &str) -> String {
+    match reqwest::Url::parse(uri) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => uri.to_string(),
+    }
+}
+
+//
+// Substitute the MessageArgs into a registry message, where "%1" refers to the
+// first argument.
+//
+fn message_format(message: &str, args: &[String]) -> String {
+    let mut msg = message.to_string();
+
+    // Go backwards so that "%1" doesn't match the start of "%10".
+    for (i, arg) in args.iter().enumerate().rev() {
+        msg = msg.replace(&format!("%{}", i + 1), arg);
+    }
+    msg
+}
+
+//
+// Determine whether the service is completing a request asynchronously, and if
+// so return the URI to poll.  That's either the task monitor in the Location
+// header of a 202 Accepted, or a Task resource that hasn't finished yet.
+//
+fn task_pending(resp: &RedfishResponse) -> Option<String> {
+    if resp.status == 202 && resp.location.is_some() {
+        return resp.location.clone();
+    }
+    match serde_json::from_str::<RedfishTask>(&resp.body) {
+        Ok(task) if !TASK_DONE_STATES.contains(&task.state.as_str()) => {
+            serde_json::from_str::<RedfishMember>(&resp.body)
+                .ok()
+                .map(|mmbr| uri_path(&mmbr.uri))
+        }
+        _ => None,
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(uri: &str, resp: &str) -> Result<T, RedfishError> {
+    serde_json::from_str(resp).map_err(|source| RedfishError::Parse {
+        uri: uri.to_string(),
+        source,
+    })
+}
+
+fn body_json<B: Serialize>(body: &B) -> Result<String, RedfishError> {
+    serde_json::to_string(body)
+        .map_err(|e| RedfishError::Config(format!("Invalid request body: {}", e)))
+}
+
+fn print_status(status: &RedfishStatus, pad: usize) {
+    let pad = " ".repeat(pad);
+
+    if let Some(state) = &status.state {
+        println!("{0}{1: <20} {2}", pad, "State:", state);
+    }
+    if let Some(health) = &status.health {
+        println!("{0}{1: <20} {2}", pad, "Health:", health);
+    }
+    if let Some(health_rollup) = &status.health_rollup {
+        println!("{0}{1: <20} {2}", pad, "Health Rollup:", health_rollup);
+    }
+}
+
+//
+// The critical and fatal thresholds shared by the fan, temperature and voltage
+// sensors.
+//
+struct SensorThresholds {
+    lower_critical: Option<f64>,
+    lower_fatal: Option<f64>,
+    upper_critical: Option<f64>,
+    upper_fatal: Option<f64>,
+}
+
+impl SensorThresholds {
+    //
+    // Returns a description of the most severe threshold that "reading" is
+    // past, if any.
+    //
+    fn check(&self, reading: Option<f64>) -> Option<String> {
+        let reading = reading?;
+
+        if let Some(limit) = self.upper_fatal.filter(|l| reading >= *l) {
+            return Some(format!("Reading is above fatal threshold ({})", limit));
+        }
+        if let Some(limit) = self.lower_fatal.filter(|l| reading <= *l) {
+            return Some(format!("Reading is below fatal threshold ({})", limit));
+        }
+        if let Some(limit) = self.upper_critical.filter(|l| reading >= *l) {
+            return Some(format!("Reading is above critical threshold ({})", limit));
+        }
+        if let Some(limit) = self.lower_critical.filter(|l| reading <= *l) {
+            return Some(format!("Reading is below critical threshold ({})", limit));
+        }
+        None
+    }
+
+    fn print(&self, pad: usize, units: &str) {
+        let pad = " ".repeat(pad);
+
+        if let Some(limit) = self.upper_fatal {
+            println!("{0}{1: <20} {2} {3}", pad, "Upper Fatal:", limit, units);
+        }
+        if let Some(limit) = self.upper_critical {
+            println!("{0}{1: <20} {2} {3}", pad, "Upper Critical:", limit, units);
+        }
+        if let Some(limit) = self.lower_critical {
+            println!("{0}{1: <20} {2} {3}", pad, "Lower Critical:", limit, units);
+        }
+        if let Some(limit) = self.lower_fatal {
+            println!("{0}{1: <20} {2} {3}", pad, "Lower Fatal:", limit, units);
+        }
+    }
+}
+
+//
+// The properties of a model, for use with $select.  These are just the keys of
+// the serialized default value, so they track the model's serde renames.
+// Annotations such as the resource's @odata.id are always returned, and aren't
+// properties that can be selected.
+//
+fn select_fields<T: Default + Serialize>() -> String {
+    match serde_json::to_value(T::default()) {
+        Ok(serde_json::Value::Object(map)) => {
+            map.keys().map(|k| k.as_str()).filter(|k| !k.starts_with('@'))
+                .collect::<Vec<&str>>().join(",")
+        }
+        _ => String::new(),
+    }
+}
+
+//
+// Fetch every member of the collection at "uri", along with its URI.  If the
+// service supports it, the members are read inline using $expand, which takes
+// one request per page of the collection rather than one per member.
+// Otherwise each member is fetched separately, restricted with $select to the
+// properties the model uses.
+//
+fn members_get<T>(client: &RedfishClient, uri: &str) -> Result<Vec<(String, T)>, RedfishError>
+    where T: DeserializeOwned + Default + Serialize {
+
+    let mut mmbrs = Vec::new();
+
+    if client.expand_supported() {
+        let sep = if uri.contains('?') { '&' } else { '?' };
+        let expand_uri = format!("{}{}$expand=.($levels=1)", uri, sep);
+        for value in client.collection::<serde_json::Value>(&expand_uri) {
+            let value = value?;
+            let mmbr_uri = match value.get("@odata.id").and_then(|id| id.as_str()) {
+                Some(id) => id.to_string(),
+                None => continue,
+            };
+
+            //
+            // A service may decline to expand some or all of the members, in
+            // which case we're left with just the link.
+            //
+            let mmbr = if value.as_object().is_some_and(|obj| obj.len() == 1) {
+                client.get(&mmbr_uri)?
+            } else {
+                serde_json::from_value(value).map_err(|e| RedfishError::Parse {
+                    uri: mmbr_uri.clone(),
+                    source: e,
+                })?
+            };
+            mmbrs.push((mmbr_uri, mmbr));
+        }
+        return Ok(mmbrs);
+    }
+
+    let select = if client.select_supported() {
+        format!("?$select={}", select_fields::<T>())
+    } else {
+        String::new()
+    };
+    for mmbr in client.members(uri) {
+        let mmbr = mmbr?;
+        let value = client.get(&format!("{}{}", mmbr.uri, select))?;
+        mmbrs.push((mmbr.uri, value));
+    }
+    Ok(mmbrs)
+}
+
+pub fn ethernet_get(client: &RedfishClient, uri: &str)
+    -> Result<Vec<RedfishEthernetIntf>, RedfishError> {
+
+    let mut intfs = Vec::new();
+
+    for (uri, mut eth) in members_get::<RedfishEthernetIntf>(client, uri)? {
+        eth.uri = uri;
+        intfs.push(eth);
+    }
+    Ok(intfs)
+}
+
+pub fn update_service_get(client: &RedfishClient)
+    -> Result<RedfishUpdateService, RedfishError> {
+
+    match &client.root()?.update_svc {
+        Some(svc) => client.get(&svc.uri),
+        None => Err(RedfishError::NotSupported { action: "UpdateService".to_string() }),
+    }
+}
+
+pub fn systems_uri(client: &RedfishClient) -> Result<String, RedfishError> {
+    match &client.root()?.systems {
+        Some(systems) => Ok(systems.uri.clone()),
+        None => Err(RedfishError::NotSupported { action: "Systems".to_string() }),
+    }
+}
+
+pub fn chassis_uri(client: &RedfishClient) -> Result<String, RedfishError> {
+    match &client.root()?.chassis {
+        Some(chassis) => Ok(chassis.uri.clone()),
+        None => Err(RedfishError::NotSupported { action: "Chassis".to_string() }),
+    }
+}
+
+pub fn firmware_get(client: &RedfishClient)
+    -> Result<Vec<RedfishSoftwareInventory>, RedfishError> {
+
+    let updsvc = update_service_get(client)?;
+    let inv = match &updsvc.fw_inventory {
+        Some(inv) => inv,
+        None => {
+            return Err(RedfishError::NotSupported { action: "FirmwareInventory".to_string() });
+        }
+    };
+    let mut fws = Vec::new();
+
+    for mmbr in client.members(&inv.uri) {
+        let mmbr = mmbr?;
+        let mut fw: RedfishSoftwareInventory = client.get(&mmbr.uri)?;
+        fw.uri = mmbr.uri.clone();
+        fws.push(fw);
+    }
+    Ok(fws)
+}
+
+//
+// Gather the LogServices of every manager and system.
+//
+pub fn log_services_get(client: &RedfishClient)
+    -> Result<Vec<RedfishLogService>, RedfishError> {
+
+    let mut svc_colls = Vec::new();
+    for mngr in managers_get(client)? {
+        if let Some(log_svcs) = mngr.log_svcs {
+            svc_colls.push(log_svcs.uri);
+        }
+    }
+    for mmbr in client.members(&systems_uri(client)?) {
+        let mmbr = mmbr?;
+        let system: RedfishSystem = client.get(&mmbr.uri)?;
+        if let Some(log_svcs) = system.log_svcs {
+            svc_colls.push(log_svcs.uri);
+        }
+    }
+
+    let mut svcs = Vec::new();
+    for uri in &svc_colls {
+        for mmbr in client.members(uri) {
+            let mmbr = mmbr?;
+            let mut svc: RedfishLogService = client.get(&mmbr.uri)?;
+            svc.uri = mmbr.uri.clone();
+            svcs.push(svc);
+        }
+    }
+    Ok(svcs)
+}
+
+//
+// Read every entry in a LogService's Entries collection.
+//
+pub fn log_entries_get(client: &RedfishClient, uri: &str)
+    -> Result<Vec<RedfishLogEntry>, RedfishError> {
+
+    let mut entries = Vec::new();
+
+    for entry in client.collection::<RedfishLogEntry>(uri) {
+        let entry = entry?;
+        if entry.id.is_some() {
+            entries.push(entry);
+        } else {
+            entries.push(client.get(&entry.uri)?);
+        }
+    }
+    Ok(entries)
+}
+
+pub fn managers_get(client: &RedfishClient) -> Result<Vec<RedfishManager>, RedfishError> {
+    let mngrs_uri = client.root()?.mngrs.uri.clone();
+    let mut mngrs = Vec::new();
+
+    for (uri, mut mngr) in members_get::<RedfishManager>(client, &mngrs_uri)? {
+        mngr.uri = uri;
+        mngrs.push(mngr);
+    }
+    Ok(mngrs)
+}
+
+pub fn memory_get(client: &RedfishClient, uri: &str)
+    -> Result<Vec<RedfishMemory>, RedfishError> {
+
+    let mut dimms = Vec::new();
+
+    for mmbr in client.members(uri) {
+        let mmbr = mmbr?;
+        let mut dimm: RedfishMemory = client.get(&mmbr.uri)?;
+        dimm.uri = mmbr.uri.clone();
+        dimms.push(dimm);
+    }
+    Ok(dimms)
+}
+
+pub fn power_get(client: &RedfishClient, uri: &str) -> Result<RedfishPower, RedfishError> {
+    let mut power: RedfishPower = client.get(uri)?;
+    power.uri = uri.to_string();
+
+    Ok(power)
+}
+
+pub fn processors_get(client: &RedfishClient, uri: &str)
+    -> Result<Vec<RedfishProcessor>, RedfishError> {
+
+    let mut chips = Vec::new();
+
+    for (uri, mut chip) in members_get::<RedfishProcessor>(client, uri)? {
+        chip.uri = uri;
+        chips.push(chip);
+    }
+    Ok(chips)
+}
+
+//
+// Walk a system's Storage collection, fetching the drives and volumes that
+// belong to each storage subsystem.
+//
+pub fn storage_get(client: &RedfishClient, uri: &str)
+    -> Result<Vec<StorageReport>, RedfishError> {
+
+    let mut reports = Vec::new();
+
+    for mmbr in client.members(uri) {
+        let mmbr = mmbr?;
+        let mut storage: RedfishStorage = client.get(&mmbr.uri)?;
+        storage.uri = mmbr.uri.clone();
+
+        let mut drives = Vec::new();
+        for drv in &storage.drives {
+            let mut drive: RedfishDrive = client.get(&drv.uri)?;
+            drive.uri = drv.uri.clone();
+            drives.push(drive);
+        }
+
+        let mut volumes = Vec::new();
+        if let Some(vols) = &storage.volumes {
+            for vol in client.members(&vols.uri) {
+                let vol = vol?;
+                let mut volume: RedfishVolume = client.get(&vol.uri)?;
+                volume.uri = vol.uri.clone();
+                volumes.push(volume);
+            }
+        }
+        reports.push(StorageReport { storage, drives, volumes });
+    }
+    Ok(reports)
+}
+
+pub fn thermal_get(client: &RedfishClient, uri: &str) -> Result<RedfishThermal, RedfishError> {
+    let mut thermal: RedfishThermal = client.get(uri)?;
+    thermal.uri = uri.to_string();
+
+    Ok(thermal)
+}
+
+//
+// The information commands first gather everything they report on into one of
+// these structures, which can then be printed either as text or as a single
+// JSON document.
+//
+#[derive(Debug, Serialize)]
+pub struct ChassisReport {
+    pub chassis: RedfishChassis,
+    pub power: Option<RedfishPower>,
+    pub thermal: Option<RedfishThermal>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ManagerReport {
+    pub manager: RedfishManager,
+    pub ethernet_interfaces: Vec<RedfishEthernetIntf>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StorageReport {
+    pub storage: RedfishStorage,
+    pub drives: Vec<RedfishDrive>,
+    pub volumes: Vec<RedfishVolume>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SystemReport {
+    pub system: RedfishSystem,
+    pub processors: Vec<RedfishProcessor>,
+    pub ethernet_interfaces: Vec<RedfishEthernetIntf>,
+}
+
+pub fn chassis_report_get(client: &RedfishClient) -> Result<Vec<ChassisReport>, RedfishError> {
+    let mut reports = Vec::new();
+
+    for mmbr in client.members(&chassis_uri(client)?) {
+        let mmbr = mmbr?;
+        let mut chassis: RedfishChassis = client.get(&mmbr.uri)?;
+        chassis.uri = mmbr.uri.to_string();
+
+        let power = match &chassis.power {
+            Some(pwr) => Some(power_get(client, &pwr.uri)?),
+            None => None,
+        };
+        let thermal = match &chassis.thermal {
+            Some(thrm) => Some(thermal_get(client, &thrm.uri)?),
+            None => None,
+        };
+        reports.push(ChassisReport { chassis, power, thermal });
+    }
+    Ok(reports)
+}
+
+pub fn manager_report_get(client: &RedfishClient) -> Result<Vec<ManagerReport>, RedfishError> {
+    let mngrs = managers_get(client)?;
+    let mut reports = Vec::new();
+
+    for manager in mngrs {
+        let ethernet_interfaces = match &manager.eth_intfs {
+            Some(eth_intfs) => ethernet_get(client, &eth_intfs.uri)?,
+            None => Vec::new(),
+        };
+        reports.push(ManagerReport { manager, ethernet_interfaces });
+    }
+    Ok(reports)
+}
+
+pub fn system_report_get(client: &RedfishClient) -> Result<Vec<SystemReport>, RedfishError> {
+    let mut reports = Vec::new();
+
+    for mmbr in client.members(&systems_uri(client)?) {
+        let mmbr = mmbr?;
+        let mut system: RedfishSystem = client.get(&mmbr.uri)?;
+        system.uri = mmbr.uri.to_string();
+
+        let processors = processors_get(client, &system.chips.uri)?;
+        let ethernet_interfaces = match &system.eth_intfs {
+            Some(eth_intfs) => ethernet_get(client, &eth_intfs.uri)?,
+            None => Vec::new(),
+        };
+        reports.push(SystemReport { system, processors, ethernet_interfaces });
+    }
+    Ok(reports)
+}
+
+fn print_json<T: Serialize>(doc: &T) -> Result<(), RedfishError> {
+    let json = serde_json::to_string_pretty(doc)
+        .map_err(|e| RedfishError::Config(format!("Failed to serialize output: {}", e)))?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn print_power(power: &RedfishPower) {
+    if !power.power_control.is_empty() {
+        println!("\n  Power Control");
+    }
+    for ctl in &power.power_control {
+        if let Some(name) = &ctl.name {
+            println!("    {0: <20} {1}", "Label:", name);
+        }
+        if let Some(watts) = ctl.consumed_watts {
+            println!("    {0: <20} {1} W", "Power Consumed:", watts);
+        }
+        if let Some(watts) = ctl.capacity_watts {
+            println!("    {0: <20} {1} W", "Power Capacity:", watts);
+        }
+        if let Some(metrics) = &ctl.metrics {
+            if let Some(interval) = metrics.interval_min {
+                println!("    {0: <20} {1} min", "Metrics Interval:", interval);
+            }
+            if let Some(watts) = metrics.min_watts {
+                println!("    {0: <20} {1} W", "Minimum Consumed:", watts);
+            }
+            if let Some(watts) = metrics.max_watts {
+                println!("    {0: <20} {1} W", "Maximum Consumed:", watts);
+            }
+            if let Some(watts) = metrics.avg_watts {
+                println!("    {0: <20} {1} W", "Average Consumed:", watts);
+            }
+        }
+        if let Some(limit) = &ctl.limit {
+            match limit.limit_watts {
+                Some(watts) => println!("    {0: <20} {1} W", "Power Limit:", watts),
+                None => println!("    {0: <20} None", "Power Limit:"),
+            }
+            if let Some(exception) = &limit.exception {
+                println!("    {0: <20} {1}", "Limit Exception:", exception);
+            }
+        }
+        if let Some(status) = &ctl.status {
+            print_status(status, 4);
+        }
+        println!();
+    }
+
+    println!("\n  Power Supplies");
+    for psu in &power.power_supplies {
+        println!("    {0: <20} {1}", "Label:", psu.name);
+        if let Some(model) = &psu.model {
+            println!("    {0: <20} {1}", "Model:", model);
+        }
+        if let Some(serial) = &psu.serial {
+            println!("    {0: <20} {1}", "Serial:", serial);
+        }
+        if let Some(fw_version) = &psu.fw_version {
+            println!("    {0: <20} {1}", "Firmware Version:", fw_version);
+        }
+        if let Some(watts) = psu.capacity_watts {
+            println!("    {0: <20} {1} W", "Capacity:", watts);
+        }
+        if let Some(watts) = psu.output_watts {
+            println!("    {0: <20} {1} W", "Output:", watts);
+        }
+        if let Some(volts) = psu.input_voltage {
+            println!("    {0: <20} {1} V", "Input Voltage:", volts);
+        }
+        print_status(&psu.status, 4);
+        println!();
+    }
+
+    if !power.redundancy.is_empty() {
+        println!("  Redundancy");
+    }
+    for redund in &power.redundancy {
+        if let Some(name) = &redund.name {
+            println!("    {0: <20} {1}", "Label:", name);
+        }
+        if let Some(mode) = &redund.mode {
+            println!("    {0: <20} {1}", "Mode:", mode);
+        }
+        if let Some(min_needed) = redund.min_needed {
+            println!("    {0: <20} {1}", "Minimum Needed:", min_needed);
+        }
+        println!("    {0: <20} {1}", "Members:", redund.members.len());
+        if let Some(status) = &redund.status {
+            print_status(status, 4);
+        }
+        println!();
+    }
+
+    if !power.voltages.is_empty() {
+        println!("  Voltages");
+    }
+    for volt in &power.voltages {
+        if let Some(name) = &volt.name {
+            println!("    {0: <20} {1}", "Label:", name);
+        }
+        if let Some(context) = &volt.context {
+            println!("    {0: <20} {1}", "Physical Context:", context);
+        }
+        if let Some(reading) = volt.reading {
+            println!("    {0: <20} {1} V", "Reading:", reading);
+        }
+        let limits = SensorThresholds {
+            lower_critical: volt.lower_critical,
+            lower_fatal: volt.lower_fatal,
+            upper_critical: volt.upper_critical,
+            upper_fatal: volt.upper_fatal,
+        };
+        limits.print(4, "V");
+        print_status(&volt.status, 4);
+        if let Some(alert) = limits.check(volt.reading) {
+            println!("    {0: <20} {1}", "ALERT:", alert);
+        }
+        println!();
+    }
+}
+
+fn print_chassis(reports: &[ChassisReport]) {
+    println!("Number of Chassis: {}", reports.len());
+    for report in reports {
+        let chassis = &report.chassis;
+        println!("Chassis Details");
+        println!("  {0: <20} {1}", "Name:", chassis.name);
+        println!("  {0: <20} {1}", "Type:", chassis.chassis_type);
+        if let Some(manufacturer) = &chassis.manufacturer {
+            println!("  {0: <20} {1}", "Manufacturer:", manufacturer);
+        }
+        println!("  {0: <20} {1}", "Serial Number:", chassis.serial_num);
+        println!("  {0: <20} {1}", "Part Number:", chassis.part_num);
+        if let Some(status) = &chassis.status {
+            print_status(status, 2);
+        }
+
+        if let Some(power) = &report.power {
+            print_power(power);
+        }
+        if let Some(thermal) = &report.thermal {
+            println!("  Fans");
+            for fan in &thermal.fans {
+                //
+                // "FanName" was deprecated in favor of "Name".  We need need to
+                // handle both cases.
+                //
+                if let Some(name) = &fan.name {
+                    println!("    {0: <20} {1}", "Label:", name);
+                } else if let Some(fanname) = &fan.fanname {
+                    println!("    {0: <20} {1}", "Label:", fanname);
+                }
+                let units = fan.reading_units.as_deref().unwrap_or("");
+                if let Some(reading) = fan.reading {
+                    println!("    {0: <20} {1} {2}", "Reading:", reading, units);
+                }
+                let limits = SensorThresholds {
+                    lower_critical: fan.lower_critical,
+                    lower_fatal: fan.lower_fatal,
+                    upper_critical: fan.upper_critical,
+                    upper_fatal: fan.upper_fatal,
+                };
+                limits.print(4, units);
+                print_status(&fan.status, 4);
+                if let Some(alert) = limits.check(fan.reading) {
+                    println!("    {0: <20} {1}", "ALERT:", alert);
+                }
+                println!();
+            }
+
+            println!("  Temperatures");
+            for temp in &thermal.temperatures {
+                if let Some(name) = &temp.name {
+                    println!("    {0: <20} {1}", "Label:", name);
+                }
+                if let Some(context) = &temp.context {
+                    println!("    {0: <20} {1}", "Physical Context:", context);
+                }
+                if let Some(reading) = temp.reading {
+                    println!("    {0: <20} {1} C", "Reading:", reading);
+                }
+                let limits = SensorThresholds {
+                    lower_critical: temp.lower_critical,
+                    lower_fatal: temp.lower_fatal,
+                    upper_critical: temp.upper_critical,
+                    upper_fatal: temp.upper_fatal,
+                };
+                limits.print(4, "C");
+                print_status(&temp.status, 4);
+                if let Some(alert) = limits.check(temp.reading) {
+                    println!("    {0: <20} {1}", "ALERT:", alert);
+                }
+                println!();
+            }
+        }
+    }
+}
+
+fn show_chassis(client: &RedfishClient, output: OutputFormat) -> Result<(), RedfishError> {
+    let reports = chassis_report_get(client)?;
+
+    match output {
+        OutputFormat::Text => print_chassis(&reports),
+        OutputFormat::Json => print_json(&serde_json::json!({ "chassis": reports }))?,
+    }
+    Ok(())
+}
+
+fn print_ethernet_intfs(eths: &[RedfishEthernetIntf])
+{
+    println!("\n  Ethernet Interfaces");
+    for eth in eths {
+        println!("    {0: <20} {1}", "Label:", eth.name);
+        if let Some(mac_addr) = &eth.mac_addr {
+            println!("    {0: <20} {1}", "MAC Address:", mac_addr);
+        }
+        if let Some(link_state) = &eth.link_state {
+            println!("    {0: <20} {1}", "Link State:", link_state);
+        }
+        if let Some(ipv4addrs) = &eth.ipv4 {
+            for ipv4 in ipv4addrs {
+                println!("    {0: <20} {1}", "IPv4 Address:", ipv4.address);
+                println!("    {0: <20} {1}", "IPv4 Subnet:", ipv4.subnet);
+                println!("    {0: <20} {1}", "IPv4 Gateway:", ipv4.gateway);
+                println!("    {0: <20} {1}", "IPv4 Source:", ipv4.origin);
+            }
+        }
+        println!("    Status");
+        print_status(&eth.status, 6);
+        println!();
+    }
+}
+
+fn print_managers(reports: &[ManagerReport]) {
+    println!("Managers");
+    for report in reports {
+        let mngr = &report.manager;
+        println!("  {0: <20} {1}", "Type:", mngr.mngr_type);
+        if let Some(model) = &mngr.model {
+            println!("  {0: <20} {1}", "Model:", model);
+        }
+        if let Some(fw_version) = &mngr.fw_version {
+            println!("  {0: <20} {1}", "Firmware Version:", fw_version);
+        }
+        let mut supp_cons = String::new();
+        if mngr.cons_graph.as_ref().is_some_and(|c| c.enabled) {
+            supp_cons.push_str("KVM ");
+        }
+        if mngr.cons_serial.as_ref().is_some_and(|c| c.enabled) {
+            supp_cons.push_str("Serial ");
+        }
+        if mngr.cons_shell.as_ref().is_some_and(|c| c.enabled) {
+            supp_cons.push_str("CLI");
+        }
+        println!("  {0: <20} {1}", "Console Types:", supp_cons);
+        println!("  Status");
+        print_status(&mngr.status, 4);
+        if mngr.eth_intfs.is_some() {
+            print_ethernet_intfs(&report.ethernet_interfaces);
+        }
+        println!();
+    }
+}
+
+fn print_systems(reports: &[SystemReport]) {
+    for report in reports {
+        let system = &report.system;
+        println!("System Details");
+        println!("  {0: <20} {1}", "Type:", system.sys_type);
+        println!("  {0: <20} {1}", "Manufacturer:", system.manufacturer);
+        println!("  {0: <20} {1}", "Model:", system.model);
+        println!("  {0: <20} {1}", "Serial Number:", system.serial_num);
+        println!("  {0: <20} {1}", "Part Number:", system.part_num);
+        if let Some(sku) = &system.sku {
+            println!("  {0: <20} {1}", "SKU:", sku);
+        };
+        if let Some(uuid) = &system.uuid {
+            println!("  {0: <20} {1}", "UUID:", uuid);
+        };
+        println!("  {0: <20} {1}", "BIOS Version:", system.bios_vers);
+        if let Some(pwr_state) = &system.pwr_state {
+            println!("  {0: <20} {1}", "Power Status:", pwr_state);
+        }
+        if let Some(locate_led) = &system.locate_led {
+            println!("  {0: <20} {1}", "Locate LED:", locate_led);
+        }
+        println!("  Status");
+        print_status(&system.memory.status, 4);
+
+        println!("\n  Processors");
+        for chip in &report.processors {
+            println!();
+            println!("    {0: <20} {1}", "Label:", chip.socket);
+            println!("    {0: <20} {1}", "Manufacturer:", chip.manufacturer);
+            println!("    {0: <20} {1}", "Brand:", chip.brand);
+            if let Some(family) = &chip.id.family {
+                println!("    {0: <20} {1}", "Family:", family);
+            }
+            if let Some(model) = &chip.id.model {
+                println!("    {0: <20} {1}", "Model:", model);
+            }
+            if let Some(stepping) = &chip.id.stepping {
+                println!("    {0: <20} {1}", "Stepping:", stepping);
+            }
+            if let Some(ucode_version) = &chip.id.ucode_version {
+                println!("    {0: <20} {1}", "Ucode Version:", ucode_version);
+            }
+            println!("    {0: <20} {1} MHz", "Speed:", chip.speed_mhz);
+            println!("    {0: <20} {1}", "Total Cores:", chip.ncores);
+            println!("    {0: <20} {1}", "Total Threads:", chip.nthreads);
+            println!("    Status");
+            print_status(&chip.status, 6);
+        }
+
+        println!("\n  Memory");
+        println!(
+            "    {0: <20} {1} GiB",
+            "Total RAM:", system.memory.total_memory
+        );
+
+        if system.eth_intfs.is_some() {
+            print_ethernet_intfs(&report.ethernet_interfaces);
+        }
+    }
+}
+
+fn show_system(client: &RedfishClient, output: OutputFormat) -> Result<(), RedfishError> {
+    let mngrs = manager_report_get(client)?;
+    let systems = system_report_get(client)?;
+
+    match output {
+        OutputFormat::Text => {
+            print_managers(&mngrs);
+            print_systems(&systems);
+        }
+        OutputFormat::Json => {
+            print_json(&serde_json::json!({ "managers": mngrs, "systems": systems }))?
+        }
+    }
+    Ok(())
+}
+
+//
+// Services differ in how they describe an empty DIMM slot: some mark it
+// "Absent" and others just report no capacity.
+//
+fn dimm_is_populated(dimm: &RedfishMemory) -> bool {
+    let absent = dimm.status.as_ref()
+        .and_then(|s| s.state.as_deref())
+        .is_some_and(|state| state == "Absent");
+
+    !absent && dimm.capacity_mib.unwrap_or(0) > 0
+}
+
+fn print_memory(dimms: &[RedfishMemory]) {
+    let (populated, empty): (Vec<&RedfishMemory>, Vec<&RedfishMemory>) =
+        dimms.iter().partition(|d| dimm_is_populated(d));
+    let total_mib: i64 = populated.iter().filter_map(|d| d.capacity_mib).sum();
+
+    println!("Memory");
+    println!("  {0: <20} {1} of {2}", "Populated Slots:", populated.len(), dimms.len());
+    println!("  {0: <20} {1} MiB", "Total Capacity:", total_mib);
+
+    println!("\n  Populated Slots");
+    for dimm in &populated {
+        println!();
+        println!("    {0: <20} {1}", "Slot:", dimm.locator.as_ref().unwrap_or(&dimm.name));
+        if let Some(capacity) = dimm.capacity_mib {
+            println!("    {0: <20} {1} MiB", "Capacity:", capacity);
+        }
+        if let Some(device_type) = &dimm.device_type {
+            println!("    {0: <20} {1}", "Type:", device_type);
+        }
+        if let Some(speed) = dimm.speed_mhz {
+            println!("    {0: <20} {1} MHz", "Speed:", speed);
+        }
+        if let Some(manufacturer) = &dimm.manufacturer {
+            println!("    {0: <20} {1}", "Manufacturer:", manufacturer);
+        }
+        if let Some(part_num) = &dimm.part_num {
+            println!("    {0: <20} {1}", "Part Number:", part_num);
+        }
+        if let Some(serial_num) = &dimm.serial_num {
+            println!("    {0: <20} {1}", "Serial Number:", serial_num);
+        }
+        if let Some(status) = &dimm.status {
+            println!("    Status");
+            print_status(status, 6);
+        }
+    }
+
+    println!("\n  Empty Slots");
+    for dimm in &empty {
+        println!("    {}", dimm.locator.as_ref().unwrap_or(&dimm.name));
+    }
+}
+
+fn print_firmware(fws: &[RedfishSoftwareInventory]) {
+    println!("Firmware Inventory");
+    for fw in fws {
+        println!();
+        println!("  {0: <20} {1}", "Label:", fw.name);
+        if let Some(version) = &fw.version {
+            println!("  {0: <20} {1}", "Version:", version);
+        }
+        if let Some(updateable) = fw.updateable {
+            println!("  {0: <20} {1}", "Updateable:", updateable);
+        }
+        if let Some(release_date) = &fw.release_date {
+            println!("  {0: <20} {1}", "Release Date:", release_date);
+        }
+        if let Some(status) = &fw.status {
+            print_status(status, 2);
+        }
+    }
+}
+
+fn show_firmware(client: &RedfishClient, output: OutputFormat) -> Result<(), RedfishError> {
+    let fws = firmware_get(client)?;
+
+    match output {
+        OutputFormat::Text => print_firmware(&fws),
+        OutputFormat::Json => print_json(&serde_json::json!({ "firmware": fws }))?,
+    }
+    Ok(())
+}
+
+fn print_log_services(svcs: &[RedfishLogService]) {
+    println!("Log Services");
+    for svc in svcs {
+        println!();
+        println!("  {0: <20} {1}", "Id:", svc.id);
+        println!("  {0: <20} {1}", "Name:", svc.name);
+        println!("  {0: <20} {1}", "URI:", svc.uri);
+        if let Some(enabled) = svc.enabled {
+            println!("  {0: <20} {1}", "Enabled:", enabled);
+        }
+        if let Some(max_records) = svc.max_records {
+            println!("  {0: <20} {1}", "Max Records:", max_records);
+        }
+        if let Some(policy) = &svc.overwrite_policy {
+            println!("  {0: <20} {1}", "Overwrite Policy:", policy);
+        }
+        let clearable = svc.actions.as_ref().is_some_and(|a| a.clear_log.is_some());
+        println!("  {0: <20} {1}", "Clearable:", clearable);
+        if let Some(status) = &svc.status {
+            print_status(status, 2);
+        }
+    }
+}
+
+fn print_log_entries(svc: &RedfishLogService, entries: &[RedfishLogEntry]) {
+    println!("Log Entries: {} ({})", svc.id, svc.uri);
+    for entry in entries {
+        println!();
+        if let Some(id) = &entry.id {
+            println!("  {0: <20} {1}", "Id:", id);
+        }
+        if let Some(created) = &entry.created {
+            println!("  {0: <20} {1}", "Created:", created);
+        }
+        if let Some(severity) = &entry.severity {
+            println!("  {0: <20} {1}", "Severity:", severity);
+        }
+        if let Some(entry_type) = &entry.entry_type {
+            println!("  {0: <20} {1}", "Entry Type:", entry_type);
+        }
+        if let Some(sensor_type) = &entry.sensor_type {
+            println!("  {0: <20} {1}", "Sensor Type:", sensor_type);
+        }
+        if let Some(message_id) = &entry.message_id {
+            println!("  {0: <20} {1}", "Message Id:", message_id);
+        }
+        if let Some(message) = &entry.message {
+            println!("  {0: <20} {1}", "Message:", message);
+        }
+    }
+}
+
+//
+// Find the LogService whose Id (or URI) is "name".  Managers and systems
+// commonly use the same Ids, so a name that matches more than one service is
+// rejected rather than guessed at.
+//
+fn log_service_find(svcs: Vec<RedfishLogService>, name: &str)
+    -> Result<RedfishLogService, RedfishError> {
+
+    let mut found: Vec<RedfishLogService> = svcs.into_iter()
+        .filter(|svc| svc.id.eq_ignore_ascii_case(name) || svc.uri == name)
+        .collect();
+
+    match found.len() {
+        0 => Err(RedfishError::NotFound { id: name.to_string() }),
+        1 => Ok(found.remove(0)),
+        _ => {
+            let uris: Vec<&str> = found.iter().map(|svc| svc.uri.as_str()).collect();
+            Err(RedfishError::Config(format!(
+                "log service {} is ambiguous, specify one of: {}", name, uris.join(", "))))
+        }
+    }
+}
+
+fn show_logs(client: &RedfishClient, name: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let svcs = log_services_get(client)?;
+
+    let name = match name {
+        Some(name) => name,
+        None => {
+            match output {
+                OutputFormat::Text => print_log_services(&svcs),
+                OutputFormat::Json => print_json(&serde_json::json!({ "log_services": svcs }))?,
+            }
+            return Ok(());
+        }
+    };
+
+    let svc = log_service_find(svcs, name)?;
+    let entries = match &svc.entries {
+        Some(entries) => log_entries_get(client, &entries.uri)?,
+        None => Vec::new(),
+    };
+    match output {
+        OutputFormat::Text => print_log_entries(&svc, &entries),
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "log_service": svc,
+            "entries": entries,
+        }))?,
+    }
+    Ok(())
+}
+
+//
+// Clear the named LogService, or if no name is given, the only LogService that
+// can be cleared.
+//
+pub fn do_clear_log(client: &RedfishClient, name: Option<&str>) -> Result<(), RedfishError> {
+    let svcs = log_services_get(client)?;
+
+    let svc = match name {
+        Some(name) => log_service_find(svcs, name)?,
+        None => {
+            let mut clearable: Vec<RedfishLogService> = svcs.into_iter()
+                .filter(|svc| svc.actions.as_ref().is_some_and(|a| a.clear_log.is_some()))
+                .collect();
+            match clearable.len() {
+                0 => return Err(RedfishError::NotSupported {
+                    action: "LogService.ClearLog".to_string()
+                }),
+                1 => clearable.remove(0),
+                _ => {
+                    let ids: Vec<&str> = clearable.iter().map(|svc| svc.id.as_str()).collect();
+                    return Err(RedfishError::Config(format!(
+                        "more than one log service can be cleared, specify one of: {}",
+                        ids.join(", "))));
+                }
+            }
+        }
+    };
+
+    let target = match svc.actions.as_ref().and_then(|a| a.clear_log.as_ref()) {
+        Some(action) => action.target.clone(),
+        None => {
+            return Err(RedfishError::NotSupported {
+                action: format!("LogService.ClearLog on {}", svc.uri)
+            });
+        }
+    };
+    client.post(&target, &serde_json::json!({}))?;
+    println!("Cleared log: {}", svc.uri);
+    Ok(())
+}
+
+//
+// The logs command takes one of:
+//
+//     (none)          list the available log services
+//     SERVICE         print the entries in SERVICE
+//     clear[:SERVICE] clear SERVICE
+//
+fn logs(client: &RedfishClient, arg: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    match arg {
+        Some("clear") => do_clear_log(client, None),
+        Some(arg) => match arg.strip_prefix("clear:") {
+            Some(name) => do_clear_log(client, Some(name)),
+            None => show_logs(client, Some(arg), output),
+        },
+        None => show_logs(client, None, output),
+    }
+}
+
+fn show_memory(client: &RedfishClient, system_id: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let dimms = match &system.dimms {
+        Some(dimms) => memory_get(client, &dimms.uri)?,
+        None => return Err(RedfishError::NotSupported { action: "Memory".to_string() }),
+    };
+
+    match output {
+        OutputFormat::Text => print_memory(&dimms),
+        OutputFormat::Json => print_json(&serde_json::json!({ "memory": dimms }))?,
+    }
+    Ok(())
+}
+
+fn capacity_fmt(bytes: i64) -> String {
+    format!("{:.1} GiB", bytes as f64 / (1u64 << 30) as f64)
+}
+
+fn print_storage(reports: &[StorageReport]) {
+    for report in reports {
+        let storage = &report.storage;
+        println!("Storage");
+        println!("  {0: <20} {1}", "Name:", storage.name);
+        if let Some(status) = &storage.status {
+            print_status(status, 2);
+        }
+
+        println!("\n  Controllers");
+        for ctlr in &storage.controllers {
+            if let Some(name) = &ctlr.name {
+                println!("    {0: <20} {1}", "Label:", name);
+            }
+            if let Some(manufacturer) = &ctlr.manufacturer {
+                println!("    {0: <20} {1}", "Manufacturer:", manufacturer);
+            }
+            if let Some(model) = &ctlr.model {
+                println!("    {0: <20} {1}", "Model:", model);
+            }
+            if let Some(serial_num) = &ctlr.serial_num {
+                println!("    {0: <20} {1}", "Serial Number:", serial_num);
+            }
+            if let Some(fw_version) = &ctlr.fw_version {
+                println!("    {0: <20} {1}", "Firmware Version:", fw_version);
+            }
+            if let Some(status) = &ctlr.status {
+                print_status(status, 4);
+            }
+            println!();
+        }
+
+        println!("  Drives");
+        for drive in &report.drives {
+            println!("    {0: <20} {1}", "Label:", drive.name);
+            if let Some(manufacturer) = &drive.manufacturer {
+                println!("    {0: <20} {1}", "Manufacturer:", manufacturer);
+            }
+            if let Some(model) = &drive.model {
+                println!("    {0: <20} {1}", "Model:", model);
+            }
+            if let Some(serial_num) = &drive.serial_num {
+                println!("    {0: <20} {1}", "Serial Number:", serial_num);
+            }
+            if let Some(capacity) = drive.capacity_bytes {
+                println!("    {0: <20} {1}", "Capacity:", capacity_fmt(capacity));
+            }
+            if let Some(media_type) = &drive.media_type {
+                println!("    {0: <20} {1}", "Media Type:", media_type);
+            }
+            if let Some(protocol) = &drive.protocol {
+                println!("    {0: <20} {1}", "Protocol:", protocol);
+            }
+            if let Some(life_left) = drive.life_left_pct {
+                println!("    {0: <20} {1}%", "Predicted Life Left:", life_left);
+            }
+            if let Some(status) = &drive.status {
+                print_status(status, 4);
+            }
+            println!();
+        }
+
+        println!("  Volumes");
+        for volume in &report.volumes {
+            println!("    {0: <20} {1}", "Label:", volume.name);
+            //
+            // "VolumeType" was deprecated in favor of "RAIDType", so fall back
+            // to it on older services.
+            //
+            if let Some(raid_type) = &volume.raid_type {
+                println!("    {0: <20} {1}", "RAID Type:", raid_type);
+            } else if let Some(volume_type) = &volume.volume_type {
+                println!("    {0: <20} {1}", "Volume Type:", volume_type);
+            }
+            if let Some(capacity) = volume.capacity_bytes {
+                println!("    {0: <20} {1}", "Capacity:", capacity_fmt(capacity));
+            }
+            if let Some(status) = &volume.status {
+                print_status(status, 4);
+            }
+            println!();
+        }
+    }
+}
+
+fn show_storage(client: &RedfishClient, system_id: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let reports = match &system.storage {
+        Some(storage) => storage_get(client, &storage.uri)?,
+        None => return Err(RedfishError::NotSupported { action: "Storage".to_string() }),
+    };
+
+    match output {
+        OutputFormat::Text => print_storage(&reports),
+        OutputFormat::Json => print_json(&serde_json::json!({ "storage": reports }))?,
+    }
+    Ok(())
+}
+
+fn print_reset_types(systems: &[RedfishSystem]) {
+    println!("Reset Types");
+    for system in systems {
+        println!();
+        println!("  {0: <20} {1}", "System:", system.id);
+        println!("  {0: <20} {1}", "URI:", system.uri);
+        match &system.actions.reset {
+            Some(action) => match &action.reset_type {
+                Some(allowed) => println!("  {0: <20} {1}", "Supported:", allowed.join(", ")),
+                None => println!("  {0: <20} Not reported", "Supported:"),
+            },
+            None => println!("  {0: <20} Reset not supported", "Supported:"),
+        }
+    }
+}
+
+//
+// List the ResetTypes that each system supports.  Unlike the action commands,
+// this defaults to every system.
+//
+fn show_reset_types(client: &RedfishClient, system_id: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let systems = systems_find(client, Some(system_id.unwrap_or("all")))?;
+
+    match output {
+        OutputFormat::Text => print_reset_types(&systems),
+        OutputFormat::Json => {
+            let types: Vec<serde_json::Value> = systems.iter().map(|system| {
+                serde_json::json!({
+                    "system": system.id,
+                    "uri": system.uri,
+                    "reset_types": system.actions.reset.as_ref().and_then(|a| a.reset_type.as_ref()),
+                })
+            }).collect();
+            print_json(&serde_json::json!({ "reset_types": types }))?
+        }
+    }
+    Ok(())
+}
+
+fn print_version(rootsvc: &RedfishRootService) {
+    println!("Redfish version: {}", rootsvc.version);
+    if let Some(vendor) = &rootsvc.vendor {
+        println!("  {0: <20} {1}", "Vendor:", vendor);
+    }
+    if let Some(product) = &rootsvc.product {
+        println!("  {0: <20} {1}", "Product:", product);
+    }
+    if let Some(uuid) = &rootsvc.uuid {
+        println!("  {0: <20} {1}", "UUID:", uuid);
+    }
+
+    println!("\nServices");
+    let sessions = rootsvc.links.as_ref().and_then(|l| l.sessions.as_ref());
+    let svcs = [
+        ("Systems:", rootsvc.systems.as_ref()),
+        ("Chassis:", rootsvc.chassis.as_ref()),
+        ("Managers:", Some(&rootsvc.mngrs)),
+        ("SessionService:", rootsvc.session_svc.as_ref()),
+        ("Sessions:", sessions),
+        ("AccountService:", rootsvc.account_svc.as_ref()),
+        ("EventService:", rootsvc.event_svc.as_ref()),
+        ("UpdateService:", rootsvc.update_svc.as_ref()),
+        ("TaskService:", rootsvc.task_svc.as_ref()),
+        ("Registries:", rootsvc.registries.as_ref()),
+        ("JsonSchemas:", rootsvc.json_schemas.as_ref()),
+    ];
+    for (label, svc) in &svcs {
+        match svc {
+            Some(svc) => println!("  {0: <20} {1}", label, svc.uri),
+            None => println!("  {0: <20} Not supported", label),
+        }
+    }
+
+    println!("\nProtocol Features");
+    let features = match &rootsvc.protocol_features {
+        Some(features) => features,
+        None => {
+            println!("  Not reported");
+            return;
+        }
+    };
+    let expand = match &features.expand_query {
+        Some(expand) => {
+            let mut opts = Vec::new();
+            let flags = [
+                ("ExpandAll", expand.expand_all),
+                ("Levels", expand.levels),
+                ("Links", expand.links),
+                ("NoLinks", expand.no_links),
+            ];
+            for (name, flag) in &flags {
+                if *flag == Some(true) {
+                    opts.push(name.to_string());
+                }
+            }
+            if let Some(max_levels) = expand.max_levels {
+                opts.push(format!("MaxLevels={}", max_levels));
+            }
+            opts.join(", ")
+        }
+        None => "false".to_string(),
+    };
+    println!("  {0: <20} {1}", "$expand:", expand);
+    let flags = [
+        ("$select:", features.select_query),
+        ("$filter:", features.filter_query),
+        ("$top/$skip:", features.top_skip_query),
+        ("only:", features.only_member_query),
+        ("excerpt:", features.excerpt_query),
+    ];
+    for (label, flag) in &flags {
+        println!("  {0: <20} {1}", label, flag.unwrap_or(false));
+    }
+}
+
+fn show_version(client: &RedfishClient, output: OutputFormat) -> Result<(), RedfishError> {
+    let rootsvc = client.root()?;
+
+    match output {
+        OutputFormat::Text => print_version(&rootsvc),
+        OutputFormat::Json => print_json(&rootsvc)?,
+    }
+    Ok(())
+}
+
+//
+// Find the systems named by "system_id", which may be a system's Id, Name,
+// UUID, SerialNumber or URI, or "all" for every system.  If no system was
+// specified, the first system is used.  A name that matches more than one
+// system is rejected rather than guessed at.
+//
+pub fn systems_find(client: &RedfishClient, system_id: Option<&str>)
+    -> Result<Vec<RedfishSystem>, RedfishError> {
+
+    let uri = systems_uri(client)?;
+
+    let id = match system_id {
+        Some(id) => id,
+        None => {
+            let mmbr = match client.members(&uri).next() {
+                Some(mmbr) => mmbr?,
+                None => return Err(RedfishError::NotFound { id: uri }),
+            };
+            let mut system: RedfishSystem = client.get(&mmbr.uri)?;
+            system.uri = mmbr.uri;
+            return Ok(vec![system]);
+        }
+    };
+
+    let mut systems = Vec::new();
+    for (uri, mut system) in members_get::<RedfishSystem>(client, &uri)? {
+        system.uri = uri;
+        systems.push(system);
+    }
+    if id == "all" {
+        if systems.is_empty() {
+            return Err(RedfishError::NotFound { id: uri });
+        }
+        return Ok(systems);
+    }
+
+    let found: Vec<RedfishSystem> = systems.into_iter()
+        .filter(|system| {
+            system.id == id
+                || system.name == id
+                || system.serial_num == id
+                || system.uri == id
+                || system.uuid.as_ref().is_some_and(|uuid| uuid.eq_ignore_ascii_case(id))
+        })
+        .collect();
+
+    match found.len() {
+        0 => Err(RedfishError::NotFound { id: id.to_string() }),
+        1 => Ok(found),
+        _ => {
+            let ids: Vec<&str> = found.iter().map(|system| system.id.as_str()).collect();
+            Err(RedfishError::Config(format!(
+                "system {} is ambiguous, specify one of: {}", id, ids.join(", "))))
+        }
+    }
+}
+
+//
+// Find the single system named by "system_id", for commands that can't act on
+// every system at once.
+//
+fn system_get(client: &RedfishClient, system_id: Option<&str>)
+    -> Result<RedfishSystem, RedfishError> {
+
+    let mut systems = systems_find(client, system_id)?;
+    match systems.len() {
+        1 => Ok(systems.remove(0)),
+        _ => Err(RedfishError::Config(
+            "this command requires a single system, not \"all\"".to_string()
+        )),
+    }
+}
+
+//
+// Check that "value" is one of the AllowableValues for "property", if the
+// service advertised any.
+//
+fn allowable_check(property: &str, value: &str, allowed: Option<&Vec<String>>)
+    -> Result<(), RedfishError> {
+
+    match allowed {
+        Some(allowed) if !allowed.iter().any(|v| v == value) => {
+            Err(RedfishError::NotSupported {
+                action: format!("{} {} (supported: {})", property, value, allowed.join(", ")),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+//
+// Override the boot source of the system.  A "boot_target" of None leaves the
+// target unchanged, which is only useful for disabling the override.  The
+// override applies to the next boot only, unless opts.boot_enabled says
+// otherwise.
+//
+pub fn do_boot(client: &RedfishClient, system_id: Option<&str>, boot_target: Option<&str>,
+    uefi_target: Option<&str>, opts: &CmdOptions) -> Result<(), RedfishError> {
+
+    let enabled = opts.boot_enabled.as_deref().unwrap_or("Once");
+
+    for system in systems_find(client, system_id)? {
+        let boot = match &system.boot {
+            Some(boot) => boot,
+            None => return Err(RedfishError::NotSupported { action: "Boot".to_string() }),
+        };
+
+        let mut data = serde_json::json!({ "BootSourceOverrideEnabled": enabled });
+        allowable_check("BootSourceOverrideEnabled", enabled, boot.override_allstates.as_ref())?;
+        if let Some(target) = boot_target {
+            allowable_check("BootSourceOverrideTarget", target,
+                boot.override_alltargets.as_ref())?;
+            data["BootSourceOverrideTarget"] = serde_json::json!(target);
+        }
+        if let Some(path) = uefi_target {
+            data["UefiTargetBootSourceOverride"] = serde_json::json!(path);
+        }
+        if let Some(mode) = &opts.boot_mode {
+            if boot.override_mode.is_none() {
+                return Err(RedfishError::NotSupported {
+                    action: "BootSourceOverrideMode".to_string(),
+                });
+            }
+            allowable_check("BootSourceOverrideMode", mode, boot.override_allmodes.as_ref())?;
+            data["BootSourceOverrideMode"] = serde_json::json!(mode);
+        }
+
+        client.patch(&system.uri, &serde_json::json!({ "Boot": data }))?;
+    }
+    Ok(())
+}
+
+//
+// The boot command takes the device to boot from next, one of:
+//
+//     pxe, hdd, cd, usb, uefihttp, biossetup
+//     uefitarget:PATH     the UEFI device path PATH
+//
+// or no device at all, with --disable, to clear any override.
+//
+fn boot(client: &RedfishClient, arg: Option<&str>, opts: &CmdOptions)
+    -> Result<(), RedfishError> {
+
+    let disable = opts.boot_enabled.as_deref() == Some("Disabled");
+    let system_id = opts.system.as_deref();
+
+    let arg = match arg {
+        Some(arg) if disable => {
+            return Err(RedfishError::Config(format!(
+                "boot target {} can't be used with --disable", arg)));
+        }
+        Some(arg) => arg,
+        None if disable => return do_boot(client, system_id, None, None, opts),
+        None => return Err(RedfishError::Config("boot requires a target".to_string())),
+    };
+
+    if let Some(path) = arg.strip_prefix("uefitarget:") {
+        if path.is_empty() {
+            return Err(RedfishError::Config("uefitarget requires a device path".to_string()));
+        }
+        return do_boot(client, system_id, Some("UefiTarget"), Some(path), opts);
+    }
+
+    let target = match arg {
+        "pxe" => "Pxe",
+        "hdd" => "Hdd",
+        "cd" => "Cd",
+        "usb" => "Usb",
+        "uefihttp" => "UefiHttp",
+        "biossetup" => "BiosSetup",
+        _ => return Err(RedfishError::Config(format!("unknown boot target: {}", arg))),
+    };
+    do_boot(client, system_id, Some(target), None, opts)
+}
+
+//
+// A system's BootOrder, along with the BootOptions it refers to, and any new
+// order that's pending in the system's settings object.
+//
+#[derive(Debug, Serialize)]
+pub struct BootOrderReport {
+    pub order: Vec<String>,
+    pub pending: Option<Vec<String>>,
+    pub options: Vec<RedfishBootOption>,
+}
+
+pub fn boot_order_get(client: &RedfishClient, system: &RedfishSystem)
+    -> Result<BootOrderReport, RedfishError> {
+
+    let boot = match &system.boot {
+        Some(boot) => boot,
+        None => return Err(RedfishError::NotSupported { action: "Boot".to_string() }),
+    };
+    let order = match &boot.boot_order {
+        Some(order) => order.clone(),
+        None => return Err(RedfishError::NotSupported { action: "BootOrder".to_string() }),
+    };
+
+    let mut options = Vec::new();
+    if let Some(opts) = &boot.boot_options {
+        for (uri, mut opt) in members_get::<RedfishBootOption>(client, &opts.uri)? {
+            opt.uri = uri;
+            options.push(opt);
+        }
+    }
+
+    let pending = match &system.settings {
+        Some(settings) => {
+            let pending: RedfishSystemSettings = client.get(&settings.settings_object.uri)?;
+            pending.boot.and_then(|boot| boot.boot_order)
+        }
+        None => None,
+    };
+    Ok(BootOrderReport { order, pending, options })
+}
+
+impl BootOrderReport {
+    //
+    // The order that will be in effect after the next reset: the pending
+    // order if there is one, otherwise the current BootOrder.
+    //
+    fn next_order(&self) -> &[String] {
+        self.pending.as_ref().unwrap_or(&self.order)
+    }
+}
+
+fn print_boot_order_list(order: &[String], options: &[RedfishBootOption]) {
+    for (idx, reference) in order.iter().enumerate() {
+        match options.iter().find(|opt| opt.reference == *reference) {
+            Some(opt) => {
+                let name = opt.display_name.as_ref().unwrap_or(&opt.id);
+                println!("  {0: <4} {1: <12} {2}", idx, reference, name);
+                if let Some(path) = &opt.uefi_path {
+                    println!("  {0: <4} {1: <12} {2}", "", "", path);
+                }
+            }
+            None => println!("  {0: <4} {1}", idx, reference),
+        }
+    }
+}
+
+fn print_boot_order(report: &BootOrderReport) {
+    println!("Boot Order");
+    print_boot_order_list(&report.order, &report.options);
+    if let Some(pending) = &report.pending {
+        if *pending != report.order {
+            println!("\nPending Boot Order");
+            print_boot_order_list(pending, &report.options);
+        }
+    }
+}
+
+//
+// Find the BootOptionReference named by "name", which may be its position in
+// the boot order (the pending order, if there is one, as that's the last one
+// shown), the reference itself, or (all or part of) the BootOption's
+// DisplayName.
+//
+fn boot_option_find(report: &BootOrderReport, name: &str) -> Result<String, RedfishError> {
+    if let Ok(idx) = name.parse::<usize>() {
+        return match report.next_order().get(idx) {
+            Some(reference) => Ok(reference.clone()),
+            None => Err(RedfishError::NotFound { id: name.to_string() }),
+        };
+    }
+    if let Some(reference) = report.order.iter().find(|r| r.eq_ignore_ascii_case(name)) {
+        return Ok(reference.clone());
+    }
+
+    let lname = name.to_lowercase();
+    let display_name = |opt: &&RedfishBootOption| {
+        opt.display_name.as_ref().map(|n| n.to_lowercase())
+    };
+    let mut found: Vec<&RedfishBootOption> = report.options.iter()
+        .filter(|opt| display_name(opt).is_some_and(|n| n == lname))
+        .collect();
+    if found.is_empty() {
+        found = report.options.iter()
+            .filter(|opt| display_name(opt).is_some_and(|n| n.contains(&lname)))
+            .collect();
+    }
+
+    match found.len() {
+        0 => Err(RedfishError::NotFound { id: name.to_string() }),
+        1 => Ok(found[0].reference.clone()),
+        _ => {
+            let refs: Vec<&str> = found.iter().map(|opt| opt.reference.as_str()).collect();
+            Err(RedfishError::Config(format!(
+                "boot option {} is ambiguous, specify one of: {}", name, refs.join(", "))))
+        }
+    }
+}
+
+//
+// Move the boot options listed in "spec", a comma separated list, to the front
+// of the BootOrder in that order.  The remaining options keep their current
+// relative order.  If the system has a settings object, the new order is
+// written there and takes effect at the next reset.  It is then built from any
+// order already pending there, so that an earlier change isn't lost.
+//
+pub fn do_boot_order(client: &RedfishClient, system_id: Option<&str>, spec: &str)
+    -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let report = boot_order_get(client, &system)?;
+
+    let mut order = Vec::new();
+    for name in spec.split(',').map(|n| n.trim()).filter(|n| !n.is_empty()) {
+        let reference = boot_option_find(&report, name)?;
+        if order.contains(&reference) {
+            return Err(RedfishError::Config(format!(
+                "boot option {} is listed more than once", reference)));
+        }
+        order.push(reference);
+    }
+    if order.is_empty() {
+        return Err(RedfishError::Config("no boot options given".to_string()));
+    }
+    for reference in report.next_order() {
+        if !order.contains(reference) {
+            order.push(reference.clone());
+        }
+    }
+
+    let data = serde_json::json!({ "Boot": { "BootOrder": order } });
+    match &system.settings {
+        Some(settings) => {
+            client.patch(&settings.settings_object.uri, &data)?;
+            println!("Boot order will be applied at the next reset");
+        }
+        None => {
+            client.patch(&system.uri, &data)?;
+            println!("Boot order set");
+        }
+    }
+    Ok(())
+}
+
+//
+// With no ARG, show the system's boot order.  Otherwise ARG is a comma
+// separated list of boot options to move to the front of the order.
+//
+fn bootorder(client: &RedfishClient, arg: Option<&str>, opts: &CmdOptions,
+    output: OutputFormat) -> Result<(), RedfishError> {
+
+    let system_id = opts.system.as_deref();
+
+    if let Some(spec) = arg {
+        return do_boot_order(client, system_id, spec);
+    }
+
+    let system = system_get(client, system_id)?;
+    let report = boot_order_get(client, &system)?;
+    match output {
+        OutputFormat::Text => print_boot_order(&report),
+        OutputFormat::Json => print_json(&serde_json::json!({ "boot_order": report }))?,
+    }
+    Ok(())
+}
+
+//
+// A system's BIOS attributes, along with the values of any attributes with
+// changes pending in its settings object.
+//
+#[derive(Debug, Serialize)]
+pub struct BiosReport {
+    pub bios: RedfishBios,
+    pub pending: BTreeMap<String, serde_json::Value>,
+}
+
+pub fn bios_get(client: &RedfishClient, system: &RedfishSystem)
+    -> Result<BiosReport, RedfishError> {
+
+    let uri = match &system.bios {
+        Some(bios) => bios.uri.clone(),
+        None => return Err(RedfishError::NotSupported { action: "Bios".to_string() }),
+    };
+    let mut bios: RedfishBios = client.get(&uri)?;
+    bios.uri = uri;
+
+    let mut pending = BTreeMap::new();
+    if let Some(settings) = &bios.settings {
+        let sd: RedfishBiosSettings = client.get(&settings.settings_object.uri)?;
+        for (name, value) in sd.attributes {
+            if bios.attributes.get(&name) != Some(&value) {
+                pending.insert(name, value);
+            }
+        }
+    }
+    Ok(BiosReport { bios, pending })
+}
+
+//
+// Look up the AttributeRegistry that describes the BIOS attributes.  Not every
+// service publishes its registry, so this is best effort.
+//
+fn bios_registry_get(client: &RedfishClient, bios: &RedfishBios)
+    -> Option<RedfishAttributeRegistry> {
+
+    bios.attribute_registry.as_ref()
+        .and_then(|name| client.registry_get(name).unwrap_or(None))
+}
+
+fn attr_fmt(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+fn print_bios(report: &BiosReport) {
+    println!("BIOS Attributes");
+    for (name, value) in &report.bios.attributes {
+        match report.pending.get(name) {
+            Some(pending) => println!("  {0: <32} {1} (pending: {2})", name, attr_fmt(value),
+                attr_fmt(pending)),
+            None => println!("  {0: <32} {1}", name, attr_fmt(value)),
+        }
+    }
+}
+
+fn print_bios_attr(report: &BiosReport, attr: Option<&RedfishAttribute>, name: &str) {
+    println!("BIOS Attribute: {}", name);
+    if let Some(value) = report.bios.attributes.get(name) {
+        println!("  {0: <20} {1}", "Current Value:", attr_fmt(value));
+    }
+    if let Some(value) = report.pending.get(name) {
+        println!("  {0: <20} {1}", "Pending Value:", attr_fmt(value));
+    }
+    let attr = match attr {
+        Some(attr) => attr,
+        None => return,
+    };
+    if let Some(display_name) = &attr.display_name {
+        println!("  {0: <20} {1}", "Display Name:", display_name);
+    }
+    if let Some(attr_type) = &attr.attr_type {
+        println!("  {0: <20} {1}", "Type:", attr_type);
+    }
+    if let Some(read_only) = attr.read_only {
+        println!("  {0: <20} {1}", "Read Only:", read_only);
+    }
+    if !attr.values.is_empty() {
+        println!("  Allowed Values:");
+        for value in &attr.values {
+            match &value.display_name {
+                Some(display_name) => println!("    {0: <18} {1}", value.name, display_name),
+                None => println!("    {}", value.name),
+            }
+        }
+    }
+    if attr.lower_bound.is_some() || attr.upper_bound.is_some() {
+        println!("  {0: <20} {1} - {2}", "Range:",
+            attr.lower_bound.map_or("".to_string(), |b| b.to_string()),
+            attr.upper_bound.map_or("".to_string(), |b| b.to_string()));
+    }
+    if let Some(help_text) = &attr.help_text {
+        println!("  {0: <20} {1}", "Help:", help_text);
+    }
+}
+
+fn registry_attr<'a>(registry: Option<&'a RedfishAttributeRegistry>, name: &str)
+    -> Option<&'a RedfishAttribute> {
+
+    registry.and_then(|r| r.entries.attributes.iter().find(|a| a.name == name))
+}
+
+//
+// Convert the text "value" given on the command line to the JSON type of the
+// attribute, as described by the registry or, failing that, the type of the
+// attribute's current value.
+//
+fn attr_value(bios: &RedfishBios, attr: Option<&RedfishAttribute>, name: &str, value: &str)
+    -> Result<serde_json::Value, RedfishError> {
+
+    let invalid = || RedfishError::Config(format!("invalid value for {}: {}", name, value));
+    let attr_type = match attr.and_then(|a| a.attr_type.as_deref()) {
+        Some(attr_type) => attr_type,
+        None => match bios.attributes.get(name) {
+            Some(v) if v.is_i64() => "Integer",
+            Some(v) if v.is_f64() => "Number",
+            Some(v) if v.is_boolean() => "Boolean",
+            _ => "String",
+        },
+    };
+
+    match attr_type {
+        "Integer" => value.parse::<i64>().map(serde_json::Value::from).map_err(|_| invalid()),
+        "Number" => value.parse::<f64>().map(serde_json::Value::from).map_err(|_| invalid()),
+        "Boolean" => value.parse::<bool>().map(serde_json::Value::from).map_err(|_| invalid()),
+        _ => Ok(serde_json::Value::from(value)),
+    }
+}
+
+//
+// Check a new attribute value against the registry, where we have one.
+//
+fn attr_check(bios: &RedfishBios, attr: Option<&RedfishAttribute>, name: &str,
+    value: &serde_json::Value) -> Result<(), RedfishError> {
+
+    if !bios.attributes.contains_key(name) {
+        return Err(RedfishError::NotFound { id: name.to_string() });
+    }
+    let attr = match attr {
+        Some(attr) => attr,
+        None => return Ok(()),
+    };
+    let invalid = |why: String| {
+        RedfishError::Config(format!("invalid value for {}: {} ({})", name, attr_fmt(value), why))
+    };
+
+    if attr.read_only == Some(true) {
+        return Err(RedfishError::Config(format!("attribute {} is read-only", name)));
+    }
+    if !attr.values.is_empty() {
+        let allowed: Vec<&str> = attr.values.iter().map(|v| v.name.as_str()).collect();
+        if !value.as_str().is_some_and(|v| allowed.contains(&v)) {
+            return Err(invalid(format!("allowed: {}", allowed.join(", "))));
+        }
+    }
+    if let Some(n) = value.as_i64() {
+        if attr.lower_bound.is_some_and(|b| n < b) || attr.upper_bound.is_some_and(|b| n > b) {
+            return Err(invalid("out of range".to_string()));
+        }
+    }
+    if let Some(v) = value.as_str() {
+        let len = v.chars().count();
+        if attr.min_length.is_some_and(|l| len < l) || attr.max_length.is_some_and(|l| len > l) {
+            return Err(invalid("wrong length".to_string()));
+        }
+    }
+    Ok(())
+}
+
+//
+// Compare the desired attribute values to the BIOS, taking pending changes
+// into account, and print and return those that differ.
+//
+fn bios_diff(report: &BiosReport, desired: &BTreeMap<String, serde_json::Value>)
+    -> BTreeMap<String, serde_json::Value> {
+
+    let mut changes = BTreeMap::new();
+
+    for (name, value) in desired {
+        let current = report.bios.attributes.get(name);
+        let effective = report.pending.get(name).or(current);
+        if effective == Some(value) {
+            continue;
+        }
+        let current = current.map_or("(none)".to_string(), attr_fmt);
+        match report.pending.get(name) {
+            Some(pending) => println!("  {0: <32} {1} -> {2} (pending: {3})", name, current,
+                attr_fmt(value), attr_fmt(pending)),
+            None => println!("  {0: <32} {1} -> {2}", name, current, attr_fmt(value)),
+        }
+        changes.insert(name.clone(), value.clone());
+    }
+    changes
+}
+
+//
+// Set the BIOS attributes in "desired", after checking them against the
+// AttributeRegistry and printing the changes that will be made.  Changes are
+// written to the settings object, if there is one, and applied at the next
+// reset.  With "dry_run", only the changes are printed.
+//
+pub fn do_bios_apply(client: &RedfishClient, report: &BiosReport,
+    registry: Option<&RedfishAttributeRegistry>, desired: &BTreeMap<String, serde_json::Value>,
+    dry_run: bool) -> Result<(), RedfishError> {
+
+    for (name, value) in desired {
+        attr_check(&report.bios, registry_attr(registry, name), name, value)?;
+    }
+
+    println!("BIOS Changes");
+    let changes = bios_diff(report, desired);
+    if changes.is_empty() {
+        println!("  None, the BIOS attributes already match");
+        return Ok(());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    let data = serde_json::json!({ "Attributes": changes });
+    match &report.bios.settings {
+        Some(settings) => {
+            client.patch(&settings.settings_object.uri, &data)?;
+            println!("BIOS changes will be applied at the next reset");
+        }
+        None => {
+            client.patch(&report.bios.uri, &data)?;
+            println!("BIOS changes applied");
+        }
+    }
+    Ok(())
+}
+
+pub fn do_bios_reset(client: &RedfishClient, system_id: Option<&str>)
+    -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let report = bios_get(client, &system)?;
+
+    match report.bios.actions.as_ref().and_then(|a| a.reset_bios.as_ref()) {
+        Some(action) => {
+            client.post(&action.target, &serde_json::json!({}))?;
+            println!("BIOS attributes will be reset to their defaults at the next reset");
+            Ok(())
+        }
+        None => Err(RedfishError::NotSupported { action: "#Bios.ResetBios".to_string() }),
+    }
+}
+
+//
+// Read the desired BIOS attributes from a JSON file, which contains either an
+// object of attribute names and values, or a Bios resource with such an object
+// in its "Attributes".
+//
+fn bios_file_read(path: &str) -> Result<BTreeMap<String, serde_json::Value>, RedfishError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| RedfishError::Config(format!("Failed to read {}: {}", path, e)))?;
+    let mut doc: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| RedfishError::Config(format!("Failed to parse {}: {}", path, e)))?;
+
+    if let Some(attrs) = doc.get_mut("Attributes") {
+        doc = attrs.take();
+    }
+    match doc {
+        serde_json::Value::Object(attrs) => Ok(attrs.into_iter().collect()),
+        _ => Err(RedfishError::Config(format!("{} does not contain a JSON object", path))),
+    }
+}
+
+//
+// Parse the NAME=VALUE[,...] settings given to bios:set into attribute values.
+//
+fn bios_settings_parse(bios: &RedfishBios, registry: Option<&RedfishAttributeRegistry>,
+    settings: &str) -> Result<BTreeMap<String, serde_json::Value>, RedfishError> {
+
+    let mut desired = BTreeMap::new();
+    for setting in settings.split(',') {
+        let (name, value) = match setting.split_once('=') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => {
+                return Err(RedfishError::Config(format!(
+                    "expected NAME=VALUE, not {}", setting)));
+            }
+        };
+        let attr = registry_attr(registry, name);
+        desired.insert(name.to_string(), attr_value(bios, attr, name, value)?);
+    }
+    Ok(desired)
+}
+
+fn show_bios(client: &RedfishClient, system_id: Option<&str>, name: Option<&str>,
+    output: OutputFormat) -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let report = bios_get(client, &system)?;
+
+    let name = match name {
+        Some(name) => name,
+        None => {
+            match output {
+                OutputFormat::Text => print_bios(&report),
+                OutputFormat::Json => print_json(&serde_json::json!({ "bios": report }))?,
+            }
+            return Ok(());
+        }
+    };
+
+    if !report.bios.attributes.contains_key(name) {
+        return Err(RedfishError::NotFound { id: name.to_string() });
+    }
+    let registry = bios_registry_get(client, &report.bios);
+    let attr = registry_attr(registry.as_ref(), name);
+    match output {
+        OutputFormat::Text => print_bios_attr(&report, attr, name),
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "name": name,
+            "value": report.bios.attributes.get(name),
+            "pending": report.pending.get(name),
+            "registry": attr,
+        }))?,
+    }
+    Ok(())
+}
+
+//
+// The bios command takes one of:
+//
+//     (none)                      show every attribute
+//     NAME                        show attribute NAME
+//     set:NAME=VALUE[,...]        set attributes
+//     diff:FILE                   show how FILE differs from the attributes
+//     apply:FILE                  set the attributes in FILE
+//     reset                       reset every attribute to its default
+//
+fn bios(client: &RedfishClient, arg: Option<&str>, opts: &CmdOptions, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let system_id = opts.system.as_deref();
+
+    let arg = match arg {
+        Some(arg) => arg,
+        None => return show_bios(client, system_id, None, output),
+    };
+    if arg == "reset" {
+        return do_bios_reset(client, system_id);
+    }
+
+    let settings = arg.strip_prefix("set:");
+    let dry_run = arg.starts_with("diff:");
+    let file = match arg.strip_prefix("diff:").or_else(|| arg.strip_prefix("apply:")) {
+        Some(path) => Some(bios_file_read(path)?),
+        None if settings.is_some() => None,
+        None => return show_bios(client, system_id, Some(arg), output),
+    };
+
+    //
+    // Fetch the BIOS and its registry once, both to interpret the new values
+    // and to check and apply them.
+    //
+    let system = system_get(client, system_id)?;
+    let report = bios_get(client, &system)?;
+    let registry = bios_registry_get(client, &report.bios);
+
+    let desired = match file {
+        Some(desired) => desired,
+        None => bios_settings_parse(&report.bios, registry.as_ref(), settings.unwrap_or(""))?,
+    };
+    do_bios_apply(client, &report, registry.as_ref(), &desired, dry_run)
+}
+
+#[derive(Debug, Serialize)]
+pub struct SecureBootDatabaseReport {
+    pub database: RedfishSecureBootDatabase,
+    pub certificates: Vec<RedfishCertificate>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SecureBootReport {
+    pub secure_boot: RedfishSecureBoot,
+    pub databases: Vec<SecureBootDatabaseReport>,
+}
+
+//
+// The UEFI databases whose certificates we report on.  Services also expose
+// the factory defaults for each (e.g. "dbDefault"), which we skip.
+//
+const SECURE_BOOT_DATABASES: [&str; 4] = ["PK", "KEK", "db", "dbx"];
+
+fn secure_boot_get(client: &RedfishClient, system: &RedfishSystem)
+    -> Result<RedfishSecureBoot, RedfishError> {
+
+    let uri = match &system.secure_boot {
+        Some(secure_boot) => secure_boot.uri.clone(),
+        None => return Err(RedfishError::NotSupported { action: "SecureBoot".to_string() }),
+    };
+    let mut secure_boot: RedfishSecureBoot = client.get(&uri)?;
+    secure_boot.uri = uri;
+    Ok(secure_boot)
+}
+
+pub fn secure_boot_report_get(client: &RedfishClient, system: &RedfishSystem)
+    -> Result<SecureBootReport, RedfishError> {
+
+    let secure_boot = secure_boot_get(client, system)?;
+
+    let mut databases = Vec::new();
+    if let Some(dbs) = &secure_boot.databases {
+        for (uri, mut database) in members_get::<RedfishSecureBootDatabase>(client, &dbs.uri)? {
+            database.uri = uri;
+            let db_id = database.database_id.as_ref().unwrap_or(&database.id);
+            if !SECURE_BOOT_DATABASES.contains(&db_id.as_str()) {
+                continue;
+            }
+
+            let mut certificates = Vec::new();
+            if let Some(certs) = &database.certificates {
+                for (uri, mut cert) in members_get::<RedfishCertificate>(client, &certs.uri)? {
+                    cert.uri = uri;
+                    certificates.push(cert);
+                }
+            }
+            databases.push(SecureBootDatabaseReport { database, certificates });
+        }
+    }
+    Ok(SecureBootReport { secure_boot, databases })
+}
+
+fn cert_name_fmt(name: &RedfishCertIdentifier) -> String {
+    match (&name.common_name, &name.organization) {
+        (Some(cn), Some(org)) => format!("{} ({})", cn, org),
+        (Some(cn), None) => cn.clone(),
+        (None, Some(org)) => org.clone(),
+        (None, None) => "Unknown".to_string(),
+    }
+}
+
+fn print_secure_boot(report: &SecureBootReport) {
+    let secure_boot = &report.secure_boot;
+
+    println!("Secure Boot");
+    if let Some(enabled) = secure_boot.enabled {
+        println!("  {0: <20} {1}", "Enabled:", enabled);
+    }
+    if let Some(current_boot) = &secure_boot.current_boot {
+        println!("  {0: <20} {1}", "Current Boot:", current_boot);
+    }
+    if let Some(mode) = &secure_boot.mode {
+        println!("  {0: <20} {1}", "Mode:", mode);
+    }
+    let reset_keys = secure_boot.actions.as_ref().and_then(|a| a.reset_keys.as_ref());
+    if let Some(types) = reset_keys.and_then(|a| a.reset_keys_type.as_ref()) {
+        println!("  {0: <20} {1}", "Reset Keys:", types.join(", "));
+    }
+
+    for db in &report.databases {
+        println!();
+        let db_id = db.database.database_id.as_ref().unwrap_or(&db.database.id);
+        println!("  {0: <20} {1}", "Database:", db_id);
+        if db.certificates.is_empty() {
+            println!("    No certificates");
+        }
+        for cert in &db.certificates {
+            let subject = cert.subject.as_ref().map_or(cert.id.clone(), cert_name_fmt);
+            println!("    {0: <20} {1}", "Certificate:", subject);
+            if let Some(cert_type) = &cert.cert_type {
+                println!("      {0: <20} {1}", "Type:", cert_type);
+            }
+            if let Some(issuer) = &cert.issuer {
+                println!("      {0: <20} {1}", "Issuer:", cert_name_fmt(issuer));
+            }
+            if let Some(valid_from) = &cert.valid_from {
+                println!("      {0: <20} {1}", "Valid From:", valid_from);
+            }
+            if let Some(valid_until) = &cert.valid_until {
+                println!("      {0: <20} {1}", "Valid Until:", valid_until);
+            }
+        }
+    }
+}
+
+pub fn do_secure_boot_enable(client: &RedfishClient, system_id: Option<&str>, enable: bool)
+    -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let secure_boot = secure_boot_get(client, &system)?;
+
+    if secure_boot.enabled.is_none() {
+        return Err(RedfishError::NotSupported { action: "SecureBootEnable".to_string() });
+    }
+    client.patch(&secure_boot.uri, &serde_json::json!({ "SecureBootEnable": enable }))?;
+    println!("Secure Boot will be {} at the next reset",
+        if enable { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+pub fn do_secure_boot_reset_keys(client: &RedfishClient, system_id: Option<&str>,
+    reset_type: &str) -> Result<(), RedfishError> {
+
+    let system = system_get(client, system_id)?;
+    let secure_boot = secure_boot_get(client, &system)?;
+
+    let action = match secure_boot.actions.as_ref().and_then(|a| a.reset_keys.as_ref()) {
+        Some(action) => action,
+        None => {
+            return Err(RedfishError::NotSupported {
+                action: "#SecureBoot.ResetKeys".to_string(),
+            });
+        }
+    };
+    allowable_check("ResetKeysType", reset_type, action.reset_keys_type.as_ref())?;
+    client.post(&action.target, &serde_json::json!({ "ResetKeysType": reset_type }))?;
+    println!("Secure Boot keys reset: {}", reset_type);
+    Ok(())
+}
+
+//
+// The secureboot command takes one of:
+//
+//     (none)              show the Secure Boot state and certificates
+//     on, off             enable or disable Secure Boot
+//     resetkeys:TYPE      reset the keys, where TYPE is default, deleteall or
+//                         deletepk
+//
+fn secureboot(client: &RedfishClient, arg: Option<&str>, opts: &CmdOptions,
+    output: OutputFormat) -> Result<(), RedfishError> {
+
+    let system_id = opts.system.as_deref();
+
+    match arg {
+        None => {
+            let system = system_get(client, system_id)?;
+            let report = secure_boot_report_get(client, &system)?;
+            match output {
+                OutputFormat::Text => print_secure_boot(&report),
+                OutputFormat::Json => print_json(&serde_json::json!({ "secure_boot": report }))?,
+            }
+            Ok(())
+        }
+        Some("on") => do_secure_boot_enable(client, system_id, true),
+        Some("off") => do_secure_boot_enable(client, system_id, false),
+        Some(arg) => {
+            let reset_type = match arg.strip_prefix("resetkeys:") {
+                Some("default") => "ResetAllKeysToDefault",
+                Some("deleteall") => "DeleteAllKeys",
+                Some("deletepk") => "DeletePK",
+                _ => {
+                    return Err(RedfishError::Config(format!(
+                        "unexpected secureboot argument: {}", arg)));
+                }
+            };
+            do_secure_boot_reset_keys(client, system_id, reset_type)
+        }
+    }
+}
+
+//
+// Gather the VirtualMedia devices of every manager and, on newer services,
+// every system.
+//
+pub fn vmedia_get(client: &RedfishClient) -> Result<Vec<RedfishVirtualMedia>, RedfishError> {
+    let mut colls = Vec::new();
+    for mngr in managers_get(client)? {
+        if let Some(vmedia) = mngr.vmedia {
+            colls.push(vmedia.uri);
+        }
+    }
+    for mmbr in client.members(&systems_uri(client)?) {
+        let mmbr = mmbr?;
+        let system: RedfishSystem = client.get(&mmbr.uri)?;
+        if let Some(vmedia) = system.vmedia {
+            colls.push(vmedia.uri);
+        }
+    }
+
+    let mut devs = Vec::new();
+    for uri in &colls {
+        for (uri, mut dev) in members_get::<RedfishVirtualMedia>(client, uri)? {
+            dev.uri = uri;
+            devs.push(dev);
+        }
+    }
+    Ok(devs)
+}
+
+fn print_vmedia(devs: &[RedfishVirtualMedia]) {
+    println!("Virtual Media");
+    for dev in devs {
+        println!();
+        println!("  {0: <20} {1}", "Id:", dev.id);
+        println!("  {0: <20} {1}", "Name:", dev.name);
+        println!("  {0: <20} {1}", "URI:", dev.uri);
+        println!("  {0: <20} {1}", "Media Types:", dev.media_types.join(", "));
+        if let Some(inserted) = dev.inserted {
+            println!("  {0: <20} {1}", "Inserted:", inserted);
+        }
+        if let Some(image) = dev.image.as_ref().or(dev.image_name.as_ref()) {
+            println!("  {0: <20} {1}", "Image:", image);
+        }
+        if let Some(connected_via) = &dev.connected_via {
+            println!("  {0: <20} {1}", "Connected Via:", connected_via);
+        }
+        if let Some(write_protected) = dev.write_protected {
+            println!("  {0: <20} {1}", "Write Protected:", write_protected);
+        }
+    }
+}
+
+//
+// Find the VirtualMedia device whose Id (or URI) is "name".  As with log
+// services, managers and systems may use the same Ids.
+//
+fn vmedia_find(devs: Vec<RedfishVirtualMedia>, name: &str)
+    -> Result<RedfishVirtualMedia, RedfishError> {
+
+    let mut found: Vec<RedfishVirtualMedia> = devs.into_iter()
+        .filter(|dev| dev.id.eq_ignore_ascii_case(name) || dev.uri == name)
+        .collect();
+
+    match found.len() {
+        0 => Err(RedfishError::NotFound { id: name.to_string() }),
+        1 => Ok(found.remove(0)),
+        _ => {
+            let uris: Vec<&str> = found.iter().map(|dev| dev.uri.as_str()).collect();
+            Err(RedfishError::Config(format!(
+                "virtual media {} is ambiguous, specify one of: {}", name, uris.join(", "))))
+        }
+    }
+}
+
+//
+// Insert the image at "image" into the named device or, if none is named, the
+// first empty device that can take it.  ISO images go to a CD or DVD device.
+// Older services without the InsertMedia action take a PATCH of Image instead.
+//
+pub fn do_vmedia_insert(client: &RedfishClient, device: Option<&str>, image: &str)
+    -> Result<(), RedfishError> {
+
+    let devs = vmedia_get(client)?;
+    let dev = match device {
+        Some(device) => vmedia_find(devs, device)?,
+        None => {
+            let iso = image.to_lowercase().ends_with(".iso");
+            let mut empty: Vec<RedfishVirtualMedia> = devs.into_iter()
+                .filter(|dev| dev.inserted != Some(true))
+                .filter(|dev| !iso || dev.media_types.iter().any(|t| t == "CD" || t == "DVD"))
+                .collect();
+            if empty.is_empty() {
+                return Err(RedfishError::NotFound {
+                    id: "empty virtual media device".to_string(),
+                });
+            }
+            empty.remove(0)
+        }
+    };
+
+    match dev.actions.as_ref().and_then(|a| a.insert_media.as_ref()) {
+        Some(action) => {
+            let data = serde_json::json!({
+                "Image": image,
+                "Inserted": true,
+                "WriteProtected": true,
+            });
+            client.post(&action.target, &data)?;
+        }
+        None => {
+            let data = serde_json::json!({ "Image": image, "Inserted": true });
+            client.patch(&dev.uri, &data)?;
+        }
+    }
+    println!("Inserted {} into {}", image, dev.uri);
+    Ok(())
+}
+
+//
+// Eject the media from the named device, or from every device with media
+// inserted if none is named.
+//
+pub fn do_vmedia_eject(client: &RedfishClient, device: Option<&str>)
+    -> Result<(), RedfishError> {
+
+    let devs = vmedia_get(client)?;
+    let devs = match device {
+        Some(device) => vec![vmedia_find(devs, device)?],
+        None => devs.into_iter().filter(|dev| dev.inserted == Some(true)).collect(),
+    };
+
+    for dev in &devs {
+        match dev.actions.as_ref().and_then(|a| a.eject_media.as_ref()) {
+            Some(action) => {
+                client.post(&action.target, &serde_json::json!({}))?;
+            }
+            None => {
+                let data = serde_json::json!({ "Image": null, "Inserted": false });
+                client.patch(&dev.uri, &data)?;
+            }
+        }
+        println!("Ejected {}", dev.uri);
+    }
+    Ok(())
+}
+
+//
+// The vmedia command takes one of:
+//
+//     (none)                  list the virtual media devices
+//     insert:[DEVICE:]IMAGE   insert the image at the URI IMAGE
+//     eject[:DEVICE]          eject the media
+//
+fn vmedia(client: &RedfishClient, arg: Option<&str>, output: OutputFormat)
+    -> Result<(), RedfishError> {
+
+    let arg = match arg {
+        Some(arg) => arg,
+        None => {
+            let devs = vmedia_get(client)?;
+            match output {
+                OutputFormat::Text => print_vmedia(&devs),
+                OutputFormat::Json => print_json(&serde_json::json!({ "virtual_media": devs }))?,
+            }
+            return Ok(());
+        }
+    };
+
+    if arg == "eject" {
+        return do_vmedia_eject(client, None);
+    }
+    if let Some(device) = arg.strip_prefix("eject:") {
+        return do_vmedia_eject(client, Some(device));
+    }
+    if let Some(rest) = arg.strip_prefix("insert:") {
+        //
+        // The image is a URI, so a leading DEVICE is anything before a colon
+        // that isn't followed by the "//" of a scheme.
+        //
+        return match rest.split_once(':') {
+            Some((device, image)) if !image.starts_with("//") => {
+                do_vmedia_insert(client, Some(device), image)
+            }
+            _ => do_vmedia_insert(client, None, rest),
+        };
+    }
+    Err(RedfishError::Config(format!("unexpected vmedia argument: {}", arg)))
+}
+
+pub fn do_identify(client: &RedfishClient, system_id: Option<&str>, ledstate: &str)
+    -> Result<(), RedfishError> {
+
+    for system in systems_find(client, system_id)? {
+        if system.locate_led.is_none() {
+            return Err(RedfishError::NotSupported { action: "IndicatorLED".to_string() });
+        }
+        let data = serde_json::json!({ "IndicatorLED": ledstate });
+        client.patch(&system.uri, &data)?;
+    }
+    Ok(())
+}
+
+//
+// The ResetType to try when the one requested isn't supported, if fallbacks
+// were allowed.
+//
+const RESET_FALLBACKS: [(&str, &str); 4] = [
+    ("GracefulRestart", "ForceRestart"),
+    ("GracefulShutdown", "ForceOff"),
+    ("On", "ForceOn"),
+    ("ForceOn", "On"),
+];
+
+//
+// Pick the ResetType to send, checking "pwrstate" against the values that the
+// system advertises.  Systems that don't advertise any are sent "pwrstate" and
+// left to reject it themselves.
+//
+fn reset_type_select(action: &RedfishActionReset, pwrstate: &str, fallback: bool)
+    -> Result<String, RedfishError> {
+
+    let allowed = match &action.reset_type {
+        Some(allowed) => allowed,
+        None => return Ok(pwrstate.to_string()),
+    };
+    if allowed.iter().any(|t| t == pwrstate) {
+        return Ok(pwrstate.to_string());
+    }
+
+    let alt = RESET_FALLBACKS.iter()
+        .find(|(from, to)| *from == pwrstate && allowed.iter().any(|t| t == to))
+        .map(|(_, to)| *to);
+    match alt {
+        Some(alt) if fallback => {
+            println!("ResetType {} not supported, using {}", pwrstate, alt);
+            Ok(alt.to_string())
+        }
+        Some(alt) => Err(RedfishError::NotSupported {
+            action: format!("ResetType {} (supported: {}; use -F to fall back to {})",
+                pwrstate, allowed.join(", "), alt),
+        }),
+        None => Err(RedfishError::NotSupported {
+            action: format!("ResetType {} (supported: {})", pwrstate, allowed.join(", ")),
+        }),
+    }
+}
+
+fn system_reset(client: &RedfishClient, system: &RedfishSystem, pwrstate: &str,
+    fallback: bool) -> Result<(), RedfishError> {
+
+    let action = match &system.actions.reset {
+        Some(action) => action,
+        None => {
+            return Err(RedfishError::NotSupported {
+                action: "#ComputerSystem.Reset".to_string(),
+            });
+        }
+    };
+    let reset_type = reset_type_select(action, pwrstate, fallback)?;
+    let data = serde_json::json!({ "ResetType": reset_type });
+    client.post(&action.target, &data)?;
+    Ok(())
+}
+
+//
+// The PowerState that a ResetType should leave the system in, if any.  The
+// restarts aren't included, as the system is On both before and after them.
+//
+fn reset_power_state(pwrstate: &str) -> Option<&'static str> {
+    match pwrstate {
+        "On" | "ForceOn" => Some("On"),
+        "GracefulShutdown" | "ForceOff" => Some("Off"),
+        _ => None,
+    }
+}
+
+//
+// Poll the system at "uri" every POWER_POLL_INTERVAL seconds until its
+// PowerState is "state", for up to "timeout" seconds.
+//
+pub fn power_state_wait(client: &RedfishClient, uri: &str, state: &str, timeout: u64)
+    -> Result<(), RedfishError> {
+
+    let start = Instant::now();
+    println!("Waiting for {} to power {}", uri, state);
+
+    loop {
+        let system: RedfishSystem = client.get(uri)?;
+        if system.pwr_state.as_deref() == Some(state) {
+            println!("{} is {}", uri, state);
+            return Ok(());
+        }
+        if start.elapsed() > Duration::from_secs(timeout) {
+            return Err(RedfishError::Timeout {
+                uri: uri.to_string(),
+                state: format!("PowerState {}", state),
+            });
+        }
+        thread::sleep(Duration::from_secs(POWER_POLL_INTERVAL));
+    }
+}
+
+pub fn do_power(client: &RedfishClient, system_id: Option<&str>, pwrstate: &str,
+    opts: &CmdOptions) -> Result<(), RedfishError> {
+
+    let systems = systems_find(client, system_id)?;
+
+    for system in &systems {
+        system_reset(client, system, pwrstate, opts.fallback)?;
+    }
+
+    //
+    // Reset every system before waiting on any of them, so that they come up
+    // (or go down) together.
+    //
+    if let (Some(timeout), Some(state)) = (opts.wait, reset_power_state(pwrstate)) {
+        for system in &systems {
+            power_state_wait(client, &system.uri, state, timeout)?;
+        }
+    }
+    Ok(())
+}
+
+//
+// Power the system off, wait for it to reach PowerState Off, and power it back
+// on.  Systems that are already off are just powered on.
+//
+pub fn do_power_cycle(client: &RedfishClient, system_id: Option<&str>, opts: &CmdOptions)
+    -> Result<(), RedfishError> {
+
+    let timeout = opts.wait.unwrap_or(POWER_WAIT_TIMEOUT);
+    let systems = systems_find(client, system_id)?;
+
+    for system in &systems {
+        if system.pwr_state.as_deref() != Some("Off") {
+            system_reset(client, system, "GracefulShutdown", opts.fallback)?;
+        }
+    }
+    for system in &systems {
+        power_state_wait(client, &system.uri, "Off", timeout)?;
+        system_reset(client, system, "On", opts.fallback)?;
+    }
+    if opts.wait.is_some() {
+        for system in &systems {
+            power_state_wait(client, &system.uri, "On", timeout)?;
+        }
+    }
+    Ok(())
+}
+
+//
+// Set (or with a limit of None, remove) the power limit on the first chassis
+// whose Power resource exposes a PowerControl.PowerLimit, and read it back to
+// make sure that the BMC applied it.
+//
+pub fn do_powercap(client: &RedfishClient, limit: Option<f64>, exception: Option<&str>)
+    -> Result<(), RedfishError> {
+
+    for mmbr in client.members(&chassis_uri(client)?) {
+        let mmbr = mmbr?;
+        let chassis: RedfishChassis = client.get(&mmbr.uri)?;
+        let power = match &chassis.power {
+            Some(pwr) => power_get(client, &pwr.uri)?,
+            None => continue,
+        };
+        let idx = match power.power_control.iter().position(|c| c.limit.is_some()) {
+            Some(idx) => idx,
+            None => continue,
+        };
+
+        //
+        // PATCHing an array element requires empty objects for the elements
+        // that precede it, so that they are left unchanged.
+        //
+        let mut pwr_limit = serde_json::json!({ "LimitInWatts": limit });
+        if let Some(exception) = exception {
+            pwr_limit["LimitException"] = serde_json::json!(exception);
+        }
+        let mut ctls = vec![serde_json::json!({}); idx];
+        ctls.push(serde_json::json!({ "PowerLimit": pwr_limit }));
+        client.patch(&power.uri, &serde_json::json!({ "PowerControl": ctls }))?;
+
+        let power = power_get(client, &power.uri)?;
+        let actual = power.power_control.get(idx)
+            .and_then(|c| c.limit.as_ref())
+            .and_then(|l| l.limit_watts);
+
+        //
+        // Many services store the limit in whole watts, so a fractional limit
+        // may read back rounded either way.
+        //
+        let applied = match (actual, limit) {
+            (Some(actual), Some(limit)) => (actual - limit).abs() < 1.0,
+            (actual, limit) => actual.is_none() && limit.is_none(),
+        };
+        if !applied {
+            let fmt_limit = |l: Option<f64>| match l {
+                Some(watts) => format!("PowerLimit of {} W", watts),
+                None => "no PowerLimit".to_string(),
+            };
+            return Err(RedfishError::Verify {
+                uri: power.uri,
+                expected: fmt_limit(limit),
+                actual: fmt_limit(actual),
+            });
+        }
+        return Ok(());
+    }
+
+    Err(RedfishError::NotSupported { action: "PowerControl.PowerLimit".to_string() })
+}
+
+fn powercap(client: &RedfishClient, arg: Option<&str>) -> Result<(), RedfishError> {
+    let arg = match arg {
+        Some(arg) => arg,
+        None => {
+            return Err(RedfishError::Config(
+                "powercap requires an argument of WATTS[:EXCEPTION] or off".to_string(),
+            ));
+        }
+    };
+
+    if arg == "off" {
+        return do_powercap(client, None, None);
+    }
+
+    //
+    // Only change the LimitException if asked to, so that setting a new limit
+    // doesn't overwrite an existing policy.
+    //
+    let (watts, exception) = match arg.split_once(':') {
+        Some((watts, exception)) => (watts, Some(exception)),
+        None => (arg, None),
+    };
+    let limit = match watts.parse::<f64>() {
+        Ok(limit) if limit > 0.0 => limit,
+        _ => return Err(RedfishError::Config(format!("invalid power limit: {}", watts))),
+    };
+    do_powercap(client, Some(limit), exception)
+}
+
+fn task_progress(task: &RedfishTask) {
+    match task.percent {
+        Some(percent) => println!("{0: <20} {1} ({2}%)", "Task State:", task.state, percent),
+        None => println!("{0: <20} {1}", "Task State:", task.state),
+    }
+    if TASK_DONE_STATES.contains(&task.state.as_str()) {
+        for msg in &task.messages {
+            println!("  {}", msg);
+        }
+    }
+}
+
+fn update_finish(resp: &RedfishResponse) {
+    match serde_json::from_str::<RedfishTask>(&resp.body) {
+        Ok(task) => println!("Update {}", task.state),
+        Err(_) if resp.status == 202 => println!("Update request accepted"),
+        Err(_) => println!("Update Completed"),
+    }
+}
+
+//
+// Ask the service to fetch and apply the firmware image at "image_uri".
+//
+pub fn do_simple_update(client: &RedfishClient, image_uri: &str)
+    -> Result<RedfishResponse, RedfishError> {
+
+    let updsvc = update_service_get(client)?;
+    let action = match &updsvc.actions.simple_update {
+        Some(action) => action,
+        None => {
+            return Err(RedfishError::NotSupported {
+                action: "#UpdateService.SimpleUpdate".to_string(),
+            });
+        }
+    };
+
+    let protocol = match image_uri.split_once("://") {
+        Some((scheme, _)) => scheme.to_uppercase(),
+        None => {
+            return Err(RedfishError::Config(format!("invalid image URI: {}", image_uri)));
+        }
+    };
+    if let Some(protocols) = &action.protocols {
+        if !protocols.contains(&protocol) {
+            return Err(RedfishError::NotSupported {
+                action: format!("TransferProtocol {}", protocol),
+            });
+        }
+    }
+
+    let data = serde_json::json!({
+        "ImageURI": image_uri,
+        "TransferProtocol": protocol,
+    });
+    client.post_action(&action.target, &data)
+}
+
+//
+// Upload the firmware image at "path" to the service, preferring the
+// multipart push URI as HttpPushUri is deprecated.
+//
+pub fn do_push_update(client: &RedfishClient, path: &Path)
+    -> Result<RedfishResponse, RedfishError> {
+
+    let updsvc = update_service_get(client)?;
+    let image = fs::read(path).map_err(|e| {
+        RedfishError::Config(format!("failed to read {}: {}", path.display(), e))
+    })?;
+
+    if let Some(uri) = &updsvc.multipart_push_uri {
+        let params = serde_json::json!({ "Targets": [] });
+        let filename = path.file_name()
+            .map(|f| f.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let form = reqwest::multipart::Form::new()
+            .part("UpdateParameters", reqwest::multipart::Part::text(params.to_string())
+                .mime_str("application/json")?)
+            .part("UpdateFile", reqwest::multipart::Part::bytes(image)
+                .file_name(filename)
+                .mime_str("application/octet-stream")?);
+        client.post_multipart(&uri_path(uri), form)
+    } else if let Some(uri) = &updsvc.http_push_uri {
+        client.post_binary(&uri_path(uri), image)
+    } else {
+        Err(RedfishError::NotSupported { action: "HttpPushUri".to_string() })
+    }
+}
+
+fn update(client: &RedfishClient, arg: Option<&str>) -> Result<(), RedfishError> {
+    let image = match arg {
+        Some(image) => image,
+        None => {
+            return Err(RedfishError::Config(
+                "update requires an image URI or file as its argument".to_string(),
+            ));
+        }
+    };
+
+    //
+    // A URI is passed to the service to fetch for itself; anything else is
+    // taken to be a local file to upload.
+    //
+    let resp = if image.contains("://") {
+        do_simple_update(client, image)?
+    } else {
+        do_push_update(client, Path::new(image))?
+    };
+    update_finish(&resp);
+    Ok(())
+}
+
+fn run_cmd(config: &Config, client: &RedfishClient) -> Result<(), RedfishError> {
+    let arg = config.cmd.arg.as_deref();
+    let opts = &config.cmd_opts;
+
+    //
+    // Commands that act on a system take it from ARG, or failing that, -s.
+    //
+    let system_id = arg.or(opts.system.as_deref());
+
+    match config.cmd.cmd.as_ref() {
+
+        "nmi" => do_power(client, system_id, "Nmi", opts)?,
+        "off" => do_power(client, system_id, "GracefulShutdown", opts)?,
+        "on" => do_power(client, system_id, "On", opts)?,
+        "reset" => do_power(client, system_id, "GracefulRestart", opts)?,
+        "forceoff" => do_power(client, system_id, "ForceOff", opts)?,
+        "forceon" => do_power(client, system_id, "ForceOn", opts)?,
+        "forcereset" => do_power(client, system_id, "ForceRestart", opts)?,
+        "cycle" => do_power_cycle(client, system_id, opts)?,
+        "biossetup" => do_boot(client, system_id, Some("BiosSetup"), None, opts)?,
+        "boot" => boot(client, arg, opts)?,
+        "bootorder" => bootorder(client, arg, opts, config.output)?,
+        "bios" => bios(client, arg, opts, config.output)?,
+        "secureboot" => secureboot(client, arg, opts, config.output)?,
+        "vmedia" => vmedia(client, arg, config.output)?,
+        "identifyoff" => do_identify(client, system_id, "Off")?,
+        "identifyon" => do_identify(client, system_id, "Blinking")?,
+        "powercap" => powercap(client, arg)?,
+        "update" => update(client, arg)?,
+
+        "chassis" => show_chassis(client, config.output)?,
+        "firmware" => show_firmware(client, config.output)?,
+        "logs" => logs(client, arg, config.output)?,
+        "memory" => show_memory(client, system_id, config.output)?,
+        "resettypes" => show_reset_types(client, system_id, config.output)?,
+        "storage" => show_storage(client, system_id, config.output)?,
+        "system" => show_system(client, config.output)?,
+        "version" => show_version(client, config.output)?,
+
+        _ => {
+            return Err(RedfishError::Config(format!(
+                "unexpected command: {}",
+                config.cmd.cmd
+            )));
+        }
+    };
+
+    Ok(())
+}
+
+pub fn run(config: &Config) -> Result<(), RedfishError> {
+    let mut client = RedfishClient::from_config(config)?;
+    client.task_progress = Some(task_progress);
+    client.login()?;
+
+    //
+    // Always log out, even if the command failed, so that we don't leak
+    // sessions on the BMC.  An error from the command takes precedence.
+    //
+    let mut result = run_cmd(config, &client);
+    if let Err(e) = &mut result {
+        client.messages_expand(e);
+    }
+    let logout = client.logout();
+
+    result.and(logout)
 }
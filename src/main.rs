@@ -18,7 +18,7 @@ use std::error::Error;
 use std::fs;
 use std::process;
 
-pub const VERSION: &'static str = "20190919";
+pub const VERSION: &str = "20190919";
 
 #[derive(Debug, Default, Deserialize)]
 pub struct ConfigFileEntry {
@@ -37,9 +37,9 @@ pub struct ConfigFile {
 
 fn usage(progname: &str, opts: &Options) {
     let msg = format!("Usage\n \
-        {} -H HOST -u USERID -p PASSWD -c CMD:[ARG] [-d] [-i] \
+        {} -H HOST -u USERID -p PASSWD -c CMD:[ARG] [-o FORMAT] [-d] [-i] \
         \nor\n \
-        {} -e ENTRY -c CMD:[ARG] [-d] [-i] \
+        {} -e ENTRY -c CMD:[ARG] [-o FORMAT] [-d] [-i] \
         \nor\n \
         {} -v",
         progname, progname, progname
@@ -50,8 +50,19 @@ fn usage(progname: &str, opts: &Options) {
     println!("---------------------");
     println!("where CMD can be:");
     println!("\tchassis\t\tShow chassis summary");
+    println!("\tfirmware\tShow firmware inventory");
+    println!("\tlogs\t\tList log services (SEL, event and manager logs)");
+    println!("\tmemory\t\tShow populated and empty DIMM slots");
+    println!("\tresettypes\tShow the reset types each system supports");
+    println!("\tstorage\t\tShow storage controllers, drives and volumes");
     println!("\tsystem\t\tShow system summary");
-    println!("\tversion\t\tShow Redfish version");
+    println!("\tversion\t\tShow Redfish version and service capabilities");
+    println!("\noptional: for memory, resettypes and storage, ARG can be a system's Id,");
+    println!("Name, UUID or SerialNumber");
+    println!("for logs, ARG can be a log service Id or URI to print its entries");
+    println!("\nInformation commands print text by default.  Use -o json to emit a");
+    println!("single JSON document instead.  Each resource in it carries its URI as");
+    println!("@odata.id.");
     println!("\nAction Commands:");
     println!("----------------");
     println!("where CMD can be:");
@@ -62,15 +73,123 @@ fn usage(progname: &str, opts: &Options) {
     println!("\tforceoff\tForce turn system off");
     println!("\tforceon\t\tForce turn system on");
     println!("\tforcereset\tForce reset system");
+    println!("\tcycle\t\tTurn system off, wait for it to power off, and turn it on");
     println!("\tbiossetup\tSet next boot to go to BIOS setup mode");
     println!("\tidentifyoff\tTurn Identify LED off");
     println!("\tidentifyon\tTurn Identify LED on");
-    println!("\noptional: where ARG can be the Redfish System ID");
-    println!("defaults to the first system");
+    println!("\noptional: where ARG can be a system's Id, Name, UUID or SerialNumber,");
+    println!("or all to act on every system.  Defaults to the first system");
+    println!("\nPower commands check the requested reset type against those the system");
+    println!("supports.  With -F, off, on, reset and forceon fall back to a related");
+    println!("reset type (e.g. reset uses ForceRestart) if the system lacks the first.");
+    println!("With -w SECS, off, on, forceoff, forceon and cycle wait up to SECS seconds");
+    println!("for the system to reach the new power state.  cycle always waits for the");
+    println!("system to power off, for up to 300 seconds unless -w is given.");
+    println!("\nBoot Override Commands:");
+    println!("-----------------------");
+    println!("\tboot:DEVICE\tBoot from DEVICE, which can be pxe, hdd, cd, usb,");
+    println!("\t\tuefihttp or biossetup");
+    println!("\tboot:uefitarget:PATH");
+    println!("\t\tBoot from the UEFI device path PATH");
+    println!("\tboot --disable\tClear the boot override");
+    println!("\nThe override applies to the next boot only, unless --persistent is given.");
+    println!("--boot-mode sets the boot mode (UEFI or Legacy) along with the device.");
+    println!("Use -s to choose the system, which defaults to the first system.");
+    println!("\nBoot Order Commands:");
+    println!("--------------------");
+    println!("\tbootorder\tShow the boot order, and any pending boot order");
+    println!("\tbootorder:OPTION[,OPTION...]");
+    println!("\t\tMove each OPTION to the front of the boot order.  OPTION can be");
+    println!("\t\tits position in the pending order (or the current order if");
+    println!("\t\tnothing is pending), its BootOptionReference or (part of) its");
+    println!("\t\tdisplay name.  Use -s to choose the system");
+    println!("\nBIOS Commands:");
+    println!("--------------");
+    println!("\tbios\t\tShow the BIOS attributes and any pending changes");
+    println!("\tbios:NAME\tShow the BIOS attribute NAME and its allowed values");
+    println!("\tbios:set:NAME=VALUE[,NAME=VALUE...]");
+    println!("\t\tSet BIOS attributes");
+    println!("\tbios:diff:FILE\tShow how the attributes in the JSON file FILE differ");
+    println!("\t\tfrom the BIOS attributes");
+    println!("\tbios:apply:FILE\tSet the BIOS attributes in the JSON file FILE");
+    println!("\tbios:reset\tReset the BIOS attributes to their defaults");
+    println!("\nChanges are checked against the BIOS AttributeRegistry where the BMC");
+    println!("publishes one, and usually take effect at the next reset.  Use -s to");
+    println!("choose the system.");
+    println!("\nSecure Boot Commands:");
+    println!("---------------------");
+    println!("\tsecureboot\tShow Secure Boot state and the PK, KEK, db and dbx");
+    println!("\t\tcertificates");
+    println!("\tsecureboot:on\tEnable Secure Boot");
+    println!("\tsecureboot:off\tDisable Secure Boot");
+    println!("\tsecureboot:resetkeys:TYPE");
+    println!("\t\tReset the Secure Boot keys.  TYPE is default (restore the");
+    println!("\t\tfactory keys), deleteall or deletepk");
+    println!("\nUse -s to choose the system.");
+    println!("\nVirtual Media Commands:");
+    println!("-----------------------");
+    println!("\tvmedia\t\tList virtual media devices");
+    println!("\tvmedia:insert:[DEVICE:]URI");
+    println!("\t\tInsert the image at URI into DEVICE, or the first empty");
+    println!("\t\tdevice that can take it");
+    println!("\tvmedia:eject[:DEVICE]");
+    println!("\t\tEject the media from DEVICE, or from every device");
+    println!("\nTo boot an ISO once, insert it and then use boot:cd.");
+    println!("\nPower Capping Commands:");
+    println!("-----------------------");
+    println!("\tpowercap:WATTS[:EXCEPTION]");
+    println!("\t\tLimit chassis power consumption to WATTS.  EXCEPTION is the");
+    println!("\t\tLimitException action (e.g. LogEventOnly or HardPowerOff), and");
+    println!("\t\tif omitted, the current LimitException is left unchanged");
+    println!("\tpowercap:off\tRemove the chassis power limit");
+    println!("\nLog Commands:");
+    println!("-------------");
+    println!("\tlogs:clear[:SERVICE]");
+    println!("\t\tClear the log service SERVICE.  SERVICE may be omitted if only");
+    println!("\t\tone log service can be cleared");
+    println!("\nFirmware Update Commands:");
+    println!("-------------------------");
+    println!("\tupdate:URI\tHave the BMC fetch and apply the image at URI");
+    println!("\tupdate:FILE\tUpload and apply the local image FILE");
+    println!("\nExit Status:");
+    println!("------------");
+    println!("\t0\tSuccess");
+    println!("\t1\tLocal error, e.g. a bad config file");
+    println!("\t2\tUsage error");
+    println!("\t3\tCould not communicate with the BMC");
+    println!("\t4\tThe BMC returned an HTTP error");
+    println!("\t5\tAuthentication failed");
+    println!("\t6\tThe requested action or reset type is not supported");
+    println!("\t7\tUnexpected response from the BMC");
+    println!("\t8\tThe requested resource was not found");
+    println!("\t9\tInvalid command or argument");
+    println!("\t10\tAn asynchronous task failed");
+    println!("\t11\tTimed out waiting for the system to change power state");
+    println!("\t12\tThe BMC accepted a change but did not apply it");
+}
+
+//
+// Each category of failure gets its own exit status so that scripts can tell,
+// for example, a bad password from a BMC that doesn't support a command.
+// Exit status 1 is reserved for local errors and 2 for usage errors.
+//
+fn exit_code(err: &redfish_util::RedfishError) -> i32 {
+    match err {
+        redfish_util::RedfishError::Transport(_) => 3,
+        redfish_util::RedfishError::Http { .. } => 4,
+        redfish_util::RedfishError::Auth { .. } => 5,
+        redfish_util::RedfishError::NotSupported { .. } => 6,
+        redfish_util::RedfishError::Parse { .. } => 7,
+        redfish_util::RedfishError::NotFound { .. } => 8,
+        redfish_util::RedfishError::Config(_) => 9,
+        redfish_util::RedfishError::Task { .. } => 10,
+        redfish_util::RedfishError::Timeout { .. } => 11,
+        redfish_util::RedfishError::Verify { .. } => 12,
+    }
 }
 
 fn read_config_file(config_path: &str) -> Result<ConfigFile, Box<dyn Error>> {
-    let config_contents = fs::read_to_string(&config_path)?;
+    let config_contents = fs::read_to_string(config_path)?;
     let cfgfile: ConfigFile = serde_json::from_str(&config_contents)?;
 
     Ok(cfgfile)
@@ -86,6 +205,17 @@ fn main()  -> Result<(), Box<dyn Error>> {
     opts.optopt("u", "user", "BMC user id", "USERID");
     opts.optopt("p", "passwd", "BMC user password", "PASSWD");
     opts.optopt("c", "command", "command", "CMD[:ARG]");
+    opts.optopt("o", "output", "Output format: text (default) or json", "FORMAT");
+    opts.optflag("F", "fallback",
+        "Let power commands use a forced reset type if the graceful one isn't supported");
+    opts.optopt("w", "wait",
+        "Wait up to SECS for power commands to reach the new power state", "SECS");
+    opts.optopt("s", "system", "System to act on, for commands whose ARG is not a system",
+        "SYSTEM");
+    opts.optflag("", "once", "Apply a boot override to the next boot only (default)");
+    opts.optflag("", "persistent", "Apply a boot override to every boot");
+    opts.optflag("", "disable", "Clear any boot override");
+    opts.optopt("", "boot-mode", "Boot override mode: UEFI or Legacy", "MODE");
     opts.optflag("d", "debug", "Enable debug messages");
     opts.optflag("i", "insecure", "Toggle insecure mode on");
     opts.optflag("h", "help", "Display this usage message");
@@ -94,7 +224,11 @@ fn main()  -> Result<(), Box<dyn Error>> {
 
     let matches = match opts.parse(&args[1..]) {
         Ok(m) => m,
-        Err(e) => panic!(e.to_string()),
+        Err(e) => {
+            eprintln!("{}", e);
+            usage(&progname, &opts);
+            process::exit(2);
+        }
     };
 
     if matches.opt_present("h") {
@@ -107,20 +241,37 @@ fn main()  -> Result<(), Box<dyn Error>> {
         process::exit(0);
     }
 
+    if !matches.free.is_empty() {
+        eprintln!("unexpected argument: {}", matches.free[0]);
+        usage(&progname, &opts);
+        process::exit(2);
+    }
+
     let debug = matches.opt_present("d");
     let insecure = matches.opt_present("i");
-    let cmd = match matches.opt_str("c") {
-        Some(c) => {
-            let v: Vec<&str> = c.split(':').collect();
-            if v.len() == 1 {
-                redfish_util::RedfishUtilCmd::new(v[0].to_string(), None)
-            } else if v.len() == 2 {
-                redfish_util::RedfishUtilCmd::new(v[0].to_string(), Some(v[1].to_string()))
-            } else {
-                eprintln!("invalid cmd argument");
+    let output = match matches.opt_str("o") {
+        Some(o) => match redfish_util::OutputFormat::from_name(&o) {
+            Some(fmt) => fmt,
+            None => {
+                eprintln!("invalid output format: {}", o);
                 usage(&progname, &opts);
                 process::exit(2);
             }
+        },
+        None => redfish_util::OutputFormat::Text,
+    };
+    let cmd = match matches.opt_str("c") {
+        Some(c) => {
+            //
+            // Only split off the command name, as some arguments (e.g. the
+            // power limit exception) contain colons of their own.
+            //
+            match c.split_once(':') {
+                Some((name, arg)) => {
+                    redfish_util::RedfishUtilCmd::new(name.to_string(), Some(arg.to_string()))
+                }
+                None => redfish_util::RedfishUtilCmd::new(c, None),
+            }
         }
         None => {
             eprintln!("-c argument is required");
@@ -130,7 +281,7 @@ fn main()  -> Result<(), Box<dyn Error>> {
     };
 
     let cfg_path = env::var("REDFISH_UTIL_CONF").ok();
-    let config = match matches.opt_str("e") {
+    let mut config = match matches.opt_str("e") {
         Some(ename) => {
             if cfg_path.is_none() {
                 eprintln!("REDFISH_UTIL_CONF is not set!");
@@ -152,7 +303,7 @@ fn main()  -> Result<(), Box<dyn Error>> {
             redfish_util::Config::new(debug, insecure,
                 cfg_file.entries[i].user.clone(),
                 cfg_file.entries[i].passwd.clone(),
-                cfg_file.entries[i].host.clone(), cmd)
+                cfg_file.entries[i].host.clone(), cmd, output)
         }
         None => {
             let host = match matches.opt_str("H") {
@@ -179,17 +330,43 @@ fn main()  -> Result<(), Box<dyn Error>> {
                     process::exit(2);
                 }
             };
-            redfish_util::Config::new(debug, insecure, user, passwd, host, cmd)
+            redfish_util::Config::new(debug, insecure, user, passwd, host, cmd,
+                output)
         }
     };
 
+    config.cmd_opts.fallback = matches.opt_present("F");
+    config.cmd_opts.system = matches.opt_str("s");
+    config.cmd_opts.boot_mode = matches.opt_str("boot-mode");
+    let boot_enabled: Vec<&str> = [("once", "Once"), ("persistent", "Continuous"),
+        ("disable", "Disabled")].iter()
+        .filter(|(flag, _)| matches.opt_present(flag))
+        .map(|(_, enabled)| *enabled)
+        .collect();
+    if boot_enabled.len() > 1 {
+        eprintln!("only one of --once, --persistent and --disable may be given");
+        usage(&progname, &opts);
+        process::exit(2);
+    }
+    config.cmd_opts.boot_enabled = boot_enabled.first().map(|e| e.to_string());
+    if let Some(secs) = matches.opt_str("w") {
+        config.cmd_opts.wait = match secs.parse::<u64>() {
+            Ok(secs) => Some(secs),
+            Err(_) => {
+                eprintln!("invalid wait timeout: {}", secs);
+                usage(&progname, &opts);
+                process::exit(2);
+            }
+        };
+    }
+
     match redfish_util::run(&config) {
         Ok(_r) => {
             process::exit(0);
         }
         Err(e) => {
-            eprintln!("An error occurred: {}", e.to_string());
-            process::exit(1);
+            eprintln!("An error occurred: {}", e);
+            process::exit(exit_code(&e));
         }
     }
 }
@@ -2,36 +2,218 @@
 // Copyright 2019 Joyent, Inc.
 //
 extern crate serde;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Deserialize)]
+extern crate serde_json;
+
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RedfishRootService {
     #[serde(rename = "RedfishVersion")]
     pub version: String,
+    #[serde(rename = "UUID")]
+    pub uuid: Option<String>,
+    #[serde(rename = "Vendor")]
+    pub vendor: Option<String>,
+    #[serde(rename = "Product")]
+    pub product: Option<String>,
+    #[serde(rename = "Systems")]
+    pub systems: Option<RedfishMember>,
+    #[serde(rename = "Chassis")]
+    pub chassis: Option<RedfishMember>,
     #[serde(rename = "Managers")]
     pub mngrs: RedfishMember,
+    #[serde(rename = "SessionService")]
+    pub session_svc: Option<RedfishMember>,
+    #[serde(rename = "AccountService")]
+    pub account_svc: Option<RedfishMember>,
+    #[serde(rename = "EventService")]
+    pub event_svc: Option<RedfishMember>,
+    #[serde(rename = "UpdateService")]
+    pub update_svc: Option<RedfishMember>,
+    #[serde(rename = "TaskService")]
+    pub task_svc: Option<RedfishMember>,
+    #[serde(rename = "Registries")]
+    pub registries: Option<RedfishMember>,
+    #[serde(rename = "JsonSchemas")]
+    pub json_schemas: Option<RedfishMember>,
+    #[serde(rename = "Links")]
+    pub links: Option<RedfishRootLinks>,
+    #[serde(rename = "ProtocolFeaturesSupported")]
+    pub protocol_features: Option<RedfishProtocolFeatures>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RedfishRootLinks {
+    #[serde(rename = "Sessions")]
+    pub sessions: Option<RedfishMember>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RedfishMember {
     #[serde(rename = "@odata.id")]
     pub uri: String,
 }
 
-#[derive(Debug, Default, Deserialize)]
-pub struct RedfishCollection {
-    #[serde(rename = "Members")]
-    pub members: Vec<RedfishMember>,
+//
+// A resource collection, or one page of one.  Most collections only contain
+// links to their members, but some (e.g. LogEntry collections) embed the
+// members themselves.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishCollection<T = RedfishMember> {
+    #[serde(rename = "Members", default = "Vec::new")]
+    pub members: Vec<T>,
+    #[serde(rename = "Members@odata.count")]
+    pub count: Option<usize>,
+    #[serde(rename = "Members@odata.nextLink")]
+    pub next_link: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishAction {
+    pub target: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishActionReset {
     pub target: String,
     #[serde(rename = "ResetType@Redfish.AllowableValues")]
     pub reset_type: Option<Vec<String>>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishActionResetKeys {
+    pub target: String,
+    #[serde(rename = "ResetKeysType@Redfish.AllowableValues")]
+    pub reset_keys_type: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishActionSimpleUpdate {
+    pub target: String,
+    #[serde(rename = "TransferProtocol@Redfish.AllowableValues")]
+    pub protocols: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishAttribute {
+    #[serde(rename = "AttributeName")]
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub attr_type: Option<String>,
+    #[serde(rename = "DisplayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "HelpText")]
+    pub help_text: Option<String>,
+    #[serde(rename = "ReadOnly")]
+    pub read_only: Option<bool>,
+    #[serde(rename = "Value", default)]
+    pub values: Vec<RedfishAttributeValue>,
+    #[serde(rename = "LowerBound")]
+    pub lower_bound: Option<i64>,
+    #[serde(rename = "UpperBound")]
+    pub upper_bound: Option<i64>,
+    #[serde(rename = "MinLength")]
+    pub min_length: Option<usize>,
+    #[serde(rename = "MaxLength")]
+    pub max_length: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishAttributeRegistry {
+    #[serde(rename = "RegistryEntries")]
+    pub entries: RedfishAttributeRegistryEntries,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishAttributeRegistryEntries {
+    #[serde(rename = "Attributes", default)]
+    pub attributes: Vec<RedfishAttribute>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishAttributeValue {
+    #[serde(rename = "ValueName")]
+    pub name: String,
+    #[serde(rename = "ValueDisplayName")]
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishBios {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "AttributeRegistry")]
+    pub attribute_registry: Option<String>,
+    #[serde(rename = "Attributes", default)]
+    pub attributes: BTreeMap<String, serde_json::Value>,
+    #[serde(rename = "Actions")]
+    pub actions: Option<RedfishBiosActions>,
+    #[serde(rename = "@Redfish.Settings")]
+    pub settings: Option<RedfishSettings>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishBiosActions {
+    #[serde(rename = "#Bios.ResetBios")]
+    pub reset_bios: Option<RedfishAction>,
+}
+
+//
+// The pending settings object of a Bios resource.  Only the attributes being
+// changed need be present.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishBiosSettings {
+    #[serde(rename = "Attributes", default)]
+    pub attributes: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishBootOption {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "BootOptionReference")]
+    pub reference: String,
+    #[serde(rename = "DisplayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "UefiDevicePath")]
+    pub uefi_path: Option<String>,
+    #[serde(rename = "BootOptionEnabled")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishCertIdentifier {
+    #[serde(rename = "CommonName")]
+    pub common_name: Option<String>,
+    #[serde(rename = "Organization")]
+    pub organization: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishCertificate {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "CertificateType")]
+    pub cert_type: Option<String>,
+    #[serde(rename = "Subject")]
+    pub subject: Option<RedfishCertIdentifier>,
+    #[serde(rename = "Issuer")]
+    pub issuer: Option<RedfishCertIdentifier>,
+    #[serde(rename = "ValidNotBefore")]
+    pub valid_from: Option<String>,
+    #[serde(rename = "ValidNotAfter")]
+    pub valid_until: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishConsole {
     #[serde(rename = "ServiceEnabled")]
     pub enabled: bool,
@@ -39,9 +221,9 @@ pub struct RedfishConsole {
     pub max_sessions: i32,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishChassis {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "Name")]
     pub name: String,
@@ -61,9 +243,53 @@ pub struct RedfishChassis {
     pub status: Option<RedfishStatus>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishDrive {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Manufacturer")]
+    pub manufacturer: Option<String>,
+    #[serde(rename = "Model")]
+    pub model: Option<String>,
+    #[serde(rename = "SerialNumber")]
+    pub serial_num: Option<String>,
+    #[serde(rename = "CapacityBytes")]
+    pub capacity_bytes: Option<i64>,
+    #[serde(rename = "MediaType")]
+    pub media_type: Option<String>,
+    #[serde(rename = "Protocol")]
+    pub protocol: Option<String>,
+    #[serde(rename = "PredictedMediaLifeLeftPercent")]
+    pub life_left_pct: Option<f64>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+//
+// The body of an error response, as described in the "Error responses"
+// section of the Redfish specification.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishErrorResponse {
+    #[serde(rename = "error")]
+    pub error: RedfishErrorInfo,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishErrorInfo {
+    #[serde(rename = "code")]
+    pub code: Option<String>,
+    #[serde(rename = "message")]
+    pub message: Option<String>,
+    #[serde(rename = "@Message.ExtendedInfo", default)]
+    pub extended_info: Vec<RedfishMessage>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishEthernetIntf {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "Name")]
     pub name: String,
@@ -93,19 +319,45 @@ pub struct RedfishEthernetIntf {
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RedfishExpandQuery {
+    #[serde(rename = "ExpandAll")]
+    pub expand_all: Option<bool>,
+    #[serde(rename = "Levels")]
+    pub levels: Option<bool>,
+    #[serde(rename = "Links")]
+    pub links: Option<bool>,
+    #[serde(rename = "NoLinks")]
+    pub no_links: Option<bool>,
+    #[serde(rename = "MaxLevels")]
+    pub max_levels: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishFan {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "FanName")]
     pub fanname: Option<String>,
     #[serde(rename = "Name")]
     pub name: Option<String>,
+    #[serde(rename = "Reading")]
+    pub reading: Option<f64>,
+    #[serde(rename = "ReadingUnits")]
+    pub reading_units: Option<String>,
+    #[serde(rename = "LowerThresholdCritical")]
+    pub lower_critical: Option<f64>,
+    #[serde(rename = "LowerThresholdFatal")]
+    pub lower_fatal: Option<f64>,
+    #[serde(rename = "UpperThresholdCritical")]
+    pub upper_critical: Option<f64>,
+    #[serde(rename = "UpperThresholdFatal")]
+    pub upper_fatal: Option<f64>,
     #[serde(rename = "Status")]
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishIpv4Address {
     #[serde(rename = "Address")]
     pub address: String,
@@ -117,9 +369,62 @@ pub struct RedfishIpv4Address {
     pub gateway: String,
 }
 
-#[derive(Debug, Default, Deserialize)]
+//
+// Most services embed the full LogEntry resources in the Members of the Entries
+// collection, but some only provide links to them.  Such entries have no "Id"
+// and must be fetched separately.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishLogEntry {
+    #[serde(rename = "@odata.id", default)]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: Option<String>,
+    #[serde(rename = "Created")]
+    pub created: Option<String>,
+    #[serde(rename = "Severity")]
+    pub severity: Option<String>,
+    #[serde(rename = "Message")]
+    pub message: Option<String>,
+    #[serde(rename = "MessageId")]
+    pub message_id: Option<String>,
+    #[serde(rename = "SensorType")]
+    pub sensor_type: Option<String>,
+    #[serde(rename = "EntryType")]
+    pub entry_type: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishLogService {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "ServiceEnabled")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "MaxNumberOfRecords")]
+    pub max_records: Option<i64>,
+    #[serde(rename = "OverWritePolicy")]
+    pub overwrite_policy: Option<String>,
+    #[serde(rename = "Entries")]
+    pub entries: Option<RedfishMember>,
+    #[serde(rename = "Actions")]
+    pub actions: Option<RedfishLogServiceActions>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishLogServiceActions {
+    #[serde(rename = "#LogService.ClearLog")]
+    pub clear_log: Option<RedfishAction>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishManager {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "ManagerType")]
     pub mngr_type: String,
@@ -137,9 +442,37 @@ pub struct RedfishManager {
     pub cons_serial: Option<RedfishConsole>,
     #[serde(rename = "CommandShell")]
     pub cons_shell: Option<RedfishConsole>,
+    #[serde(rename = "LogServices")]
+    pub log_svcs: Option<RedfishMember>,
+    #[serde(rename = "VirtualMedia")]
+    pub vmedia: Option<RedfishMember>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishMemory {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "DeviceLocator")]
+    pub locator: Option<String>,
+    #[serde(rename = "CapacityMiB")]
+    pub capacity_mib: Option<i64>,
+    #[serde(rename = "MemoryDeviceType")]
+    pub device_type: Option<String>,
+    #[serde(rename = "OperatingSpeedMhz")]
+    pub speed_mhz: Option<i64>,
+    #[serde(rename = "Manufacturer")]
+    pub manufacturer: Option<String>,
+    #[serde(rename = "PartNumber")]
+    pub part_num: Option<String>,
+    #[serde(rename = "SerialNumber")]
+    pub serial_num: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishMemorySummary {
     #[serde(rename = "TotalSystemMemoryGiB")]
     pub total_memory: i32,
@@ -147,15 +480,101 @@ pub struct RedfishMemorySummary {
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishMessage {
+    #[serde(rename = "MessageId")]
+    pub id: String,
+    #[serde(rename = "Message")]
+    pub message: Option<String>,
+    #[serde(rename = "MessageArgs", default)]
+    pub args: Vec<String>,
+    #[serde(rename = "Severity")]
+    pub severity: Option<String>,
+    #[serde(rename = "Resolution")]
+    pub resolution: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishMessageRegistry {
+    #[serde(rename = "RegistryPrefix")]
+    pub prefix: String,
+    #[serde(rename = "RegistryVersion")]
+    pub version: String,
+    #[serde(rename = "Messages")]
+    pub messages: HashMap<String, RedfishRegistryMessage>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishMessageRegistryFile {
+    #[serde(rename = "Registry")]
+    pub registry: String,
+    #[serde(rename = "Location")]
+    pub location: Vec<RedfishMessageRegistryLocation>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishMessageRegistryLocation {
+    #[serde(rename = "Language")]
+    pub language: Option<String>,
+    #[serde(rename = "Uri")]
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishPower {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
+    #[serde(rename = "PowerControl", default)]
+    pub power_control: Vec<RedfishPowerControl>,
     #[serde(rename = "PowerSupplies")]
     pub power_supplies: Vec<RedfishPowerSupply>,
+    #[serde(rename = "Voltages", default)]
+    pub voltages: Vec<RedfishVoltage>,
+    #[serde(rename = "Redundancy", default)]
+    pub redundancy: Vec<RedfishRedundancy>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishPowerControl {
+    #[serde(rename = "MemberId")]
+    pub member_id: Option<String>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "PowerConsumedWatts")]
+    pub consumed_watts: Option<f64>,
+    #[serde(rename = "PowerCapacityWatts")]
+    pub capacity_watts: Option<f64>,
+    #[serde(rename = "PowerMetrics")]
+    pub metrics: Option<RedfishPowerMetrics>,
+    #[serde(rename = "PowerLimit")]
+    pub limit: Option<RedfishPowerLimit>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishPowerLimit {
+    #[serde(rename = "LimitInWatts")]
+    pub limit_watts: Option<f64>,
+    #[serde(rename = "LimitException")]
+    pub exception: Option<String>,
+    #[serde(rename = "CorrectionInMs")]
+    pub correction_ms: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishPowerMetrics {
+    #[serde(rename = "IntervalInMin")]
+    pub interval_min: Option<i64>,
+    #[serde(rename = "MinConsumedWatts")]
+    pub min_watts: Option<f64>,
+    #[serde(rename = "MaxConsumedWatts")]
+    pub max_watts: Option<f64>,
+    #[serde(rename = "AverageConsumedWatts")]
+    pub avg_watts: Option<f64>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishPowerSupply {
     #[serde(rename = "Name")]
     pub name: String,
@@ -163,11 +582,21 @@ pub struct RedfishPowerSupply {
     pub model: Option<String>,
     #[serde(rename = "SerialNumber")]
     pub serial: Option<String>,
+    #[serde(rename = "FirmwareVersion")]
+    pub fw_version: Option<String>,
+    #[serde(rename = "PowerCapacityWatts")]
+    pub capacity_watts: Option<f64>,
+    #[serde(rename = "LastPowerOutputWatts")]
+    pub output_watts: Option<f64>,
+    #[serde(rename = "LineInputVoltage")]
+    pub input_voltage: Option<f64>,
+    #[serde(rename = "Redundancy", default)]
+    pub redundancy: Vec<RedfishMember>,
     #[serde(rename = "Status")]
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishProcessorId {
     #[serde(rename = "EffectiveFamily")]
     pub family: Option<String>,
@@ -179,9 +608,9 @@ pub struct RedfishProcessorId {
     pub ucode_version: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishProcessor {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "Socket")]
     pub socket: String,
@@ -205,7 +634,7 @@ pub struct RedfishProcessor {
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishProcessorSummary {
     #[serde(rename = "Count")]
     pub ncpus: i32,
@@ -215,7 +644,124 @@ pub struct RedfishProcessorSummary {
     pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RedfishProtocolFeatures {
+    #[serde(rename = "ExpandQuery")]
+    pub expand_query: Option<RedfishExpandQuery>,
+    #[serde(rename = "SelectQuery")]
+    pub select_query: Option<bool>,
+    #[serde(rename = "FilterQuery")]
+    pub filter_query: Option<bool>,
+    #[serde(rename = "TopSkipQuery")]
+    pub top_skip_query: Option<bool>,
+    #[serde(rename = "OnlyMemberQuery")]
+    pub only_member_query: Option<bool>,
+    #[serde(rename = "ExcerptQuery")]
+    pub excerpt_query: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishRedundancy {
+    #[serde(rename = "@odata.id")]
+    pub uri: Option<String>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "Mode")]
+    pub mode: Option<String>,
+    #[serde(rename = "MinNumNeeded")]
+    pub min_needed: Option<i32>,
+    #[serde(rename = "MaxNumSupported")]
+    pub max_supported: Option<i32>,
+    #[serde(rename = "RedundancySet", default)]
+    pub members: Vec<RedfishMember>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishRegistryMessage {
+    #[serde(rename = "Message")]
+    pub message: String,
+    #[serde(rename = "Resolution")]
+    pub resolution: Option<String>,
+    #[serde(rename = "Severity")]
+    pub severity: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSecureBoot {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "SecureBootEnable")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "SecureBootCurrentBoot")]
+    pub current_boot: Option<String>,
+    #[serde(rename = "SecureBootMode")]
+    pub mode: Option<String>,
+    #[serde(rename = "SecureBootDatabases")]
+    pub databases: Option<RedfishMember>,
+    #[serde(rename = "Actions")]
+    pub actions: Option<RedfishSecureBootActions>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSecureBootActions {
+    #[serde(rename = "#SecureBoot.ResetKeys")]
+    pub reset_keys: Option<RedfishActionResetKeys>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSecureBootDatabase {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "DatabaseId")]
+    pub database_id: Option<String>,
+    #[serde(rename = "Certificates")]
+    pub certificates: Option<RedfishMember>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSessionService {
+    #[serde(rename = "Sessions")]
+    pub sessions: RedfishMember,
+}
+
+//
+// The @Redfish.Settings annotation, which is present on resources whose
+// changes must be made to a separate settings object and are only applied
+// later, e.g. at the next reset.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSettings {
+    #[serde(rename = "SettingsObject")]
+    pub settings_object: RedfishMember,
+    #[serde(rename = "Time")]
+    pub time: Option<String>,
+    #[serde(rename = "SupportedApplyTimes")]
+    pub apply_times: Option<Vec<String>>,
+    #[serde(rename = "Messages", default)]
+    pub messages: Vec<RedfishMessage>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSoftwareInventory {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Version")]
+    pub version: Option<String>,
+    #[serde(rename = "Updateable")]
+    pub updateable: Option<bool>,
+    #[serde(rename = "ReleaseDate")]
+    pub release_date: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishStatus {
     #[serde(rename = "State")]
     pub state: Option<String>,
@@ -225,10 +771,44 @@ pub struct RedfishStatus {
     pub health_rollup: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishStorage {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "StorageControllers", default)]
+    pub controllers: Vec<RedfishStorageController>,
+    #[serde(rename = "Drives", default)]
+    pub drives: Vec<RedfishMember>,
+    #[serde(rename = "Volumes")]
+    pub volumes: Option<RedfishMember>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishStorageController {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "Manufacturer")]
+    pub manufacturer: Option<String>,
+    #[serde(rename = "Model")]
+    pub model: Option<String>,
+    #[serde(rename = "SerialNumber")]
+    pub serial_num: Option<String>,
+    #[serde(rename = "FirmwareVersion")]
+    pub fw_version: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishSystem {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
     #[serde(rename = "Name")]
     pub name: String,
     #[serde(rename = "Description")]
@@ -259,34 +839,194 @@ pub struct RedfishSystem {
     pub chips: RedfishMember,
     #[serde(rename = "MemorySummary")]
     pub memory: RedfishMemorySummary,
+    #[serde(rename = "Memory")]
+    pub dimms: Option<RedfishMember>,
     #[serde(rename = "EthernetInterfaces")]
     pub eth_intfs: Option<RedfishMember>,
+    #[serde(rename = "Storage")]
+    pub storage: Option<RedfishMember>,
+    #[serde(rename = "LogServices")]
+    pub log_svcs: Option<RedfishMember>,
+    #[serde(rename = "Bios")]
+    pub bios: Option<RedfishMember>,
+    #[serde(rename = "SecureBoot")]
+    pub secure_boot: Option<RedfishMember>,
+    #[serde(rename = "VirtualMedia")]
+    pub vmedia: Option<RedfishMember>,
     #[serde(rename = "Actions")]
     pub actions: RedfishSystemActions,
     #[serde(rename = "Boot")]
     pub boot: Option<RedfishSystemBoot>,
+    #[serde(rename = "@Redfish.Settings")]
+    pub settings: Option<RedfishSettings>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishSystemActions {
     #[serde(rename = "#ComputerSystem.Reset")]
     pub reset: Option<RedfishActionReset>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishSystemBoot {
     #[serde(rename = "BootSourceOverrideEnabled")]
     pub override_state: Option<String>,
+    #[serde(rename = "BootSourceOverrideEnabled@Redfish.AllowableValues")]
+    pub override_allstates: Option<Vec<String>>,
     #[serde(rename = "BootSourceOverrideTarget")]
     pub override_target: Option<String>,
     #[serde(rename = "BootSourceOverrideTarget@Redfish.AllowableValues")]
     pub override_alltargets: Option<Vec<String>>,
+    #[serde(rename = "BootSourceOverrideMode")]
+    pub override_mode: Option<String>,
+    #[serde(rename = "BootSourceOverrideMode@Redfish.AllowableValues")]
+    pub override_allmodes: Option<Vec<String>>,
+    #[serde(rename = "UefiTargetBootSourceOverride")]
+    pub uefi_target: Option<String>,
+    #[serde(rename = "BootOrder")]
+    pub boot_order: Option<Vec<String>>,
+    #[serde(rename = "BootOptions")]
+    pub boot_options: Option<RedfishMember>,
+}
+
+//
+// The pending settings object of a ComputerSystem, which needn't contain any
+// more of the system than the pending changes.
+//
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishSystemSettings {
+    #[serde(rename = "Boot")]
+    pub boot: Option<RedfishSystemBoot>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishTask {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "TaskState")]
+    pub state: String,
+    #[serde(rename = "TaskStatus")]
+    pub status: Option<String>,
+    #[serde(rename = "PercentComplete")]
+    pub percent: Option<i32>,
+    #[serde(rename = "Messages", default)]
+    pub messages: Vec<RedfishMessage>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishTemperature {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "ReadingCelsius")]
+    pub reading: Option<f64>,
+    #[serde(rename = "LowerThresholdCritical")]
+    pub lower_critical: Option<f64>,
+    #[serde(rename = "LowerThresholdFatal")]
+    pub lower_fatal: Option<f64>,
+    #[serde(rename = "UpperThresholdCritical")]
+    pub upper_critical: Option<f64>,
+    #[serde(rename = "UpperThresholdFatal")]
+    pub upper_fatal: Option<f64>,
+    #[serde(rename = "PhysicalContext")]
+    pub context: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: RedfishStatus,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishVolume {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "RAIDType")]
+    pub raid_type: Option<String>,
+    #[serde(rename = "VolumeType")]
+    pub volume_type: Option<String>,
+    #[serde(rename = "CapacityBytes")]
+    pub capacity_bytes: Option<i64>,
+    #[serde(rename = "Status")]
+    pub status: Option<RedfishStatus>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishUpdateService {
+    #[serde(rename = "FirmwareInventory")]
+    pub fw_inventory: Option<RedfishMember>,
+    #[serde(rename = "HttpPushUri")]
+    pub http_push_uri: Option<String>,
+    #[serde(rename = "MultipartHttpPushUri")]
+    pub multipart_push_uri: Option<String>,
+    #[serde(rename = "Actions", default)]
+    pub actions: RedfishUpdateServiceActions,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishUpdateServiceActions {
+    #[serde(rename = "#UpdateService.SimpleUpdate")]
+    pub simple_update: Option<RedfishActionSimpleUpdate>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishVirtualMedia {
+    #[serde(skip_deserializing, rename = "@odata.id")]
+    pub uri: String,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "MediaTypes", default)]
+    pub media_types: Vec<String>,
+    #[serde(rename = "Inserted")]
+    pub inserted: Option<bool>,
+    #[serde(rename = "Image")]
+    pub image: Option<String>,
+    #[serde(rename = "ImageName")]
+    pub image_name: Option<String>,
+    #[serde(rename = "ConnectedVia")]
+    pub connected_via: Option<String>,
+    #[serde(rename = "WriteProtected")]
+    pub write_protected: Option<bool>,
+    #[serde(rename = "Actions")]
+    pub actions: Option<RedfishVirtualMediaActions>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishVirtualMediaActions {
+    #[serde(rename = "#VirtualMedia.InsertMedia")]
+    pub insert_media: Option<RedfishAction>,
+    #[serde(rename = "#VirtualMedia.EjectMedia")]
+    pub eject_media: Option<RedfishAction>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RedfishVoltage {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "ReadingVolts")]
+    pub reading: Option<f64>,
+    #[serde(rename = "LowerThresholdCritical")]
+    pub lower_critical: Option<f64>,
+    #[serde(rename = "LowerThresholdFatal")]
+    pub lower_fatal: Option<f64>,
+    #[serde(rename = "UpperThresholdCritical")]
+    pub upper_critical: Option<f64>,
+    #[serde(rename = "UpperThresholdFatal")]
+    pub upper_fatal: Option<f64>,
+    #[serde(rename = "PhysicalContext")]
+    pub context: Option<String>,
+    #[serde(rename = "Status")]
+    pub status: RedfishStatus,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct RedfishThermal {
-    #[serde(skip)]
+    #[serde(skip_deserializing, rename = "@odata.id")]
     pub uri: String,
     #[serde(rename = "Fans")]
     pub fans: Vec<RedfishFan>,
+    #[serde(rename = "Temperatures", default)]
+    pub temperatures: Vec<RedfishTemperature>,
 }